@@ -11,7 +11,7 @@ use cw721::{
 };
 use cw721_base::MinterResponse;
 use sg721::InstantiateMsg;
-use sg721_base::msg::{CollectionInfoResponse, QueryMsg};
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg, SupplyResponse};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -22,6 +22,7 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(CollectionInfoResponse), &out_dir);
+    export_schema(&schema_for!(SupplyResponse), &out_dir);
     export_schema_with_title(
         &schema_for!(AllNftInfoResponse<Empty>),
         &out_dir,
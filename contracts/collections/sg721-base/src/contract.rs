@@ -2,12 +2,13 @@ use cw721_base::state::TokenInfo;
 use url::Url;
 
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, ContractInfoResponse, Decimal, Deps, DepsMut, Empty, Env, Event,
-    MessageInfo, Response, StdError, StdResult, Storage, Timestamp, WasmQuery,
+    coin, to_json_binary, Addr, BankMsg, Binary, Coin, ContractInfoResponse, Decimal, Deps,
+    DepsMut, Empty, Env, Event, MessageInfo, Response, StdError, StdResult, Storage, Timestamp,
+    WasmQuery,
 };
 
 use cw721::{ContractInfoResponse as CW721ContractInfoResponse, Cw721Execute};
-use cw_utils::nonpayable;
+use cw_utils::{may_pay, nonpayable};
 use serde::{de::DeserializeOwned, Serialize};
 
 use sg721::{
@@ -15,7 +16,7 @@ use sg721::{
     UpdateCollectionInfoMsg,
 };
 
-use crate::msg::{CollectionInfoResponse, NftParams, QueryMsg};
+use crate::msg::{CollectionInfoResponse, NftParams, QueryMsg, SupplyResponse};
 use crate::{ContractError, Sg721Contract};
 
 use crate::entry::{CONTRACT_NAME, CONTRACT_VERSION};
@@ -85,12 +86,16 @@ where
             explicit_content: msg.collection_info.explicit_content,
             start_trading_time: msg.collection_info.start_trading_time,
             royalty_info,
+            max_supply: msg.collection_info.max_supply,
+            enforce_royalties_on_transfer: msg.collection_info.enforce_royalties_on_transfer,
         };
 
         self.collection_info.save(deps.storage, &collection_info)?;
 
         self.frozen_collection_info.save(deps.storage, &false)?;
 
+        self.minted_count.save(deps.storage, &0)?;
+
         self.royalty_updated_at
             .save(deps.storage, &env.block.time)?;
 
@@ -114,18 +119,26 @@ where
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
-            } => self
-                .parent
-                .transfer_nft(deps, env, info, recipient, token_id)
-                .map_err(|e| e.into()),
+                sale_price,
+            } => {
+                let royalty_msgs = self.royalty_on_transfer(deps.as_ref(), &info, sale_price)?;
+                self.parent
+                    .transfer_nft(deps, env, info, recipient, token_id)
+                    .map(|res| res.add_messages(royalty_msgs))
+                    .map_err(|e| e.into())
+            }
             ExecuteMsg::SendNft {
                 contract,
                 token_id,
                 msg,
-            } => self
-                .parent
-                .send_nft(deps, env, info, contract, token_id, msg)
-                .map_err(|e| e.into()),
+                sale_price,
+            } => {
+                let royalty_msgs = self.royalty_on_transfer(deps.as_ref(), &info, sale_price)?;
+                self.parent
+                    .send_nft(deps, env, info, contract, token_id, msg)
+                    .map(|res| res.add_messages(royalty_msgs))
+                    .map_err(|e| e.into())
+            }
             ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -188,6 +201,48 @@ where
         }
     }
 
+    /// When the collection enforces royalties, transfers must attach exactly the royalty
+    /// share of the declared sale price, which is forwarded to the royalty payment address.
+    /// The sale price is taken as declared and must be non zero, nothing stops a sender from
+    /// understating it otherwise
+    pub fn royalty_on_transfer(
+        &self,
+        deps: Deps,
+        info: &MessageInfo,
+        sale_price: Option<Coin>,
+    ) -> Result<Vec<BankMsg>, ContractError> {
+        let collection_info = self.collection_info.load(deps.storage)?;
+        if !collection_info
+            .enforce_royalties_on_transfer
+            .unwrap_or(false)
+        {
+            return Ok(vec![]);
+        }
+        let royalty_info = match collection_info.royalty_info {
+            Some(royalty_info) => royalty_info,
+            None => return Ok(vec![]),
+        };
+        let sale_price = sale_price.ok_or(ContractError::RoyaltyNotPaid {})?;
+        // a zero price would waive the royalty altogether
+        if sale_price.amount.is_zero() {
+            return Err(ContractError::RoyaltyNotPaid {});
+        }
+
+        let royalty = sale_price.amount * royalty_info.share;
+        let paid =
+            may_pay(info, &sale_price.denom).map_err(|_| ContractError::RoyaltyNotPaid {})?;
+        if paid != royalty {
+            return Err(ContractError::RoyaltyNotPaid {});
+        }
+        if royalty.is_zero() {
+            return Ok(vec![]);
+        }
+        Ok(vec![BankMsg::Send {
+            to_address: royalty_info.payment_address.to_string(),
+            amount: vec![coin(royalty.u128(), sale_price.denom)],
+        }])
+    }
+
     pub fn update_collection_info(
         &self,
         deps: DepsMut,
@@ -201,9 +256,17 @@ where
             return Err(ContractError::CollectionInfoFrozen {});
         }
 
-        // only creator can update collection info
+        // only creator can update collection info, the minter can only forward display fields
         if collection.creator != info.sender {
-            return Err(ContractError::Unauthorized {});
+            let is_minter =
+                cw_ownable::get_ownership(deps.storage)?.owner.as_ref() == Some(&info.sender);
+            if !is_minter
+                || collection_msg.creator.is_some()
+                || collection_msg.royalty_info.is_some()
+                || collection_msg.explicit_content != collection.explicit_content
+            {
+                return Err(ContractError::Unauthorized {});
+            }
         }
 
         if let Some(new_creator) = collection_msg.creator {
@@ -233,13 +296,6 @@ where
         collection.explicit_content = collection_msg.explicit_content;
 
         if let Some(Some(new_royalty_info_response)) = collection_msg.royalty_info {
-            let last_royalty_update = self.royalty_updated_at.load(deps.storage)?;
-            if last_royalty_update.plus_seconds(24 * 60 * 60) > env.block.time {
-                return Err(ContractError::InvalidRoyalties(
-                    "Royalties can only be updated once per day".to_string(),
-                ));
-            }
-
             let new_royalty_info = RoyaltyInfo {
                 payment_address: deps
                     .api
@@ -247,6 +303,24 @@ where
                 share: share_validate(new_royalty_info_response.share)?,
             };
 
+            // lowering the share to the same address only benefits holders, so it is not rate limited
+            let is_decrease = collection
+                .royalty_info
+                .as_ref()
+                .is_some_and(|old_royalty_info| {
+                    new_royalty_info.share < old_royalty_info.share
+                        && new_royalty_info.payment_address == old_royalty_info.payment_address
+                });
+
+            if !is_decrease {
+                let last_royalty_update = self.royalty_updated_at.load(deps.storage)?;
+                if last_royalty_update.plus_seconds(24 * 60 * 60) > env.block.time {
+                    return Err(ContractError::InvalidRoyalties(
+                        "Royalties can only be updated once per day".to_string(),
+                    ));
+                }
+            }
+
             if let Some(old_royalty_info) = collection.royalty_info {
                 if old_royalty_info.share < new_royalty_info.share {
                     let share_delta = new_royalty_info.share.abs_diff(old_royalty_info.share);
@@ -265,8 +339,10 @@ where
             }
 
             collection.royalty_info = Some(new_royalty_info);
-            self.royalty_updated_at
-                .save(deps.storage, &env.block.time)?;
+            if !is_decrease {
+                self.royalty_updated_at
+                    .save(deps.storage, &env.block.time)?;
+            }
         }
 
         self.collection_info.save(deps.storage, &collection)?;
@@ -328,6 +404,15 @@ where
             } => (token_id, owner, token_uri, extension),
         };
 
+        // double check the supply cap, even if the minter already enforces one
+        let minted_count = self.load_minted_count(deps.storage)?;
+        let collection_info = self.collection_info.load(deps.storage)?;
+        if let Some(max_supply) = collection_info.max_supply {
+            if minted_count >= max_supply as u64 {
+                return Err(ContractError::MaxSupplyReached {});
+            }
+        }
+
         // create the token
         let token = TokenInfo {
             owner: deps.api.addr_validate(&owner)?,
@@ -343,6 +428,7 @@ where
             })?;
 
         self.parent.increment_tokens(deps.storage)?;
+        self.minted_count.save(deps.storage, &(minted_count + 1))?;
 
         let mut res = Response::new()
             .add_attribute("action", "mint")
@@ -358,6 +444,7 @@ where
     pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
             QueryMsg::CollectionInfo {} => to_json_binary(&self.query_collection_info(deps)?),
+            QueryMsg::Supply {} => to_json_binary(&self.query_supply(deps)?),
             _ => self.parent.query(deps, env, msg.into()),
         }
     }
@@ -384,6 +471,24 @@ where
         })
     }
 
+    pub fn query_supply(&self, deps: Deps) -> StdResult<SupplyResponse> {
+        let info = self.collection_info.load(deps.storage)?;
+        let minted = self.load_minted_count(deps.storage)?;
+
+        Ok(SupplyResponse {
+            minted,
+            max_supply: info.max_supply,
+        })
+    }
+
+    /// Collections instantiated before the counter existed fall back to the token count
+    fn load_minted_count(&self, storage: &dyn Storage) -> StdResult<u64> {
+        match self.minted_count.may_load(storage)? {
+            Some(minted_count) => Ok(minted_count),
+            None => self.parent.token_count(storage),
+        }
+    }
+
     pub fn migrate(mut deps: DepsMut, env: Env, _msg: Empty) -> Result<Response, ContractError> {
         let prev_contract_version = cw2::get_contract_version(deps.storage)?;
 
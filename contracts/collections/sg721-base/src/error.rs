@@ -50,6 +50,12 @@ pub enum ContractError {
     #[error("MinterNotFound")]
     MinterNotFound {},
 
+    #[error("MaxSupplyReached")]
+    MaxSupplyReached {},
+
+    #[error("RoyaltyNotPaid")]
+    RoyaltyNotPaid {},
+
     #[error("Ownership Update Error: {error}")]
     OwnershipUpdateError { error: String },
 
@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{
-    coin, Addr, BankMsg, Binary, Empty, Event, StdError, StdResult, Timestamp, Uint128,
+    coin, Addr, BankMsg, Binary, Coin, Empty, Event, StdError, StdResult, Timestamp, Uint128,
 };
 use cw721::{
     AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NftInfoResponse,
@@ -19,13 +19,24 @@ use sg_std::{Response, SubMsg, NATIVE_DENOM};
 #[cw_serde]
 pub enum ExecuteMsg<T, E> {
     /// Transfer is a base message to move a token to another account without triggering actions
-    TransferNft { recipient: String, token_id: String },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+        /// Sale price declared by the sender, required when the collection enforces royalties on
+        /// transfer. Informational only: it is not verified, so it can be understated. Can be
+        /// left out when the collection does not enforce royalties
+        sale_price: Option<Coin>,
+    },
     /// Send is a base message to transfer a token to a contract and trigger an action
     /// on the receiving contract.
     SendNft {
         contract: String,
         token_id: String,
         msg: Binary,
+        /// Sale price declared by the sender, required when the collection enforces royalties on
+        /// transfer. Informational only: it is not verified, so it can be understated. Can be
+        /// left out when the collection does not enforce royalties
+        sale_price: Option<Coin>,
     },
     /// Allows operator to transfer / send the token from the owner's account.
     /// If expiration is set, then this allowance has a time/height limit
@@ -119,6 +130,8 @@ pub enum QueryMsg {
     Minter {},
     #[returns(MinterResponse)]
     CollectionInfo {},
+    #[returns(SupplyResponse)]
+    Supply {},
 }
 
 impl From<QueryMsg> for Cw721QueryMsg<Empty> {
@@ -233,6 +246,12 @@ impl CollectionInfoResponse {
     }
 }
 
+#[cw_serde]
+pub struct SupplyResponse {
+    pub minted: u64,
+    pub max_supply: Option<u32>,
+}
+
 #[cw_serde]
 pub enum NftParams<T> {
     NftData {
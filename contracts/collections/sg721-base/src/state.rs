@@ -16,6 +16,8 @@ where
     /// Instantiate set to false by the minter, then true by creator to freeze collection info
     pub frozen_collection_info: Item<'a, bool>,
     pub royalty_updated_at: Item<'a, Timestamp>,
+    /// Number of tokens ever minted, burns do not decrease it
+    pub minted_count: Item<'a, u64>,
 }
 
 impl<'a, T> Default for Sg721Contract<'a, T>
@@ -28,6 +30,7 @@ where
             collection_info: Item::new("collection_info"),
             frozen_collection_info: Item::new("frozen_collection_info"),
             royalty_updated_at: Item::new("royalty_updated_at"),
+            minted_count: Item::new("minted_count"),
         }
     }
 }
@@ -169,6 +169,8 @@ mod tests {
                 explicit_content: Some(false),
                 start_trading_time: None,
                 royalty_info: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         };
         contract
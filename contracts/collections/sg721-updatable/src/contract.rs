@@ -310,6 +310,8 @@ mod tests {
                 explicit_content: None,
                 start_trading_time: None,
                 royalty_info: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
@@ -406,6 +408,8 @@ mod tests {
                 explicit_content: None,
                 start_trading_time: None,
                 royalty_info: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Binary;
+use cosmwasm_std::Coin;
 use cosmwasm_std::Timestamp;
 use cw_utils::Expiration;
 use sg721::{RoyaltyInfoResponse, UpdateCollectionInfoMsg};
@@ -21,11 +22,15 @@ pub enum ExecuteMsg<T, E> {
     TransferNft {
         recipient: String,
         token_id: String,
+        /// Self-declared and unverified, see sg721-base
+        sale_price: Option<Coin>,
     },
     SendNft {
         contract: String,
         token_id: String,
         msg: Binary,
+        /// Self-declared and unverified, see sg721-base
+        sale_price: Option<Coin>,
     },
     Approve {
         spender: String,
@@ -78,18 +83,22 @@ where
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
+                sale_price,
             } => Sg721ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
+                sale_price,
             },
             ExecuteMsg::SendNft {
                 contract,
                 token_id,
                 msg,
+                sale_price,
             } => Sg721ExecuteMsg::SendNft {
                 contract,
                 token_id,
                 msg,
+                sale_price,
             },
             ExecuteMsg::Approve {
                 spender,
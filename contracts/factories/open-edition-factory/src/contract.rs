@@ -1,9 +1,12 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg,
+    ensure, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
+    Response, StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use cw_utils::{maybe_addr, parse_reply_instantiate_data};
 use sg_std::NATIVE_DENOM;
 
 use base_factory::contract::{
@@ -11,19 +14,26 @@ use base_factory::contract::{
 };
 use base_factory::ContractError as BaseContractError;
 use sg1::checked_fair_burn;
-use sg2::query::{AllowedCollectionCodeIdResponse, AllowedCollectionCodeIdsResponse, Sg2QueryMsg};
+use sg2::query::{AllowedCollectionCodeIdResponse, AllowedCollectionCodeIdsResponse};
+use sg4::{QueryMsg as MinterQueryMsg, StatusResponse};
 
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, OpenEditionMinterCreateMsg, OpenEditionMinterInitMsgExtension,
-    OpenEditionUpdateParamsMsg, ParamsResponse, SudoMsg,
+    ExecuteMsg, InstantiateMsg, MintersResponse, OpenEditionMinterCreateMsg,
+    OpenEditionMinterInitMsgExtension, OpenEditionUpdateParamsMsg, ParamsResponse, QueryMsg,
+    StatusBatchResponse, SudoMsg,
 };
-use crate::state::SUDO_PARAMS;
+use crate::state::{OpenEditionMinterParams, MINTERS, SUDO_PARAMS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:open-edition-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const INSTANTIATE_MINTER_REPLY_ID: u64 = 1;
+const PAGINATION_DEFAULT_LIMIT: u32 = 25;
+const PAGINATION_MAX_LIMIT: u32 = 100;
+const MAX_STATUS_BATCH_SIZE: usize = 50;
+
 /// Can only be called by governance
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -39,12 +49,26 @@ pub fn instantiate(
         params.creation_fee.denom == NATIVE_DENOM,
         BaseContractError::InvalidDenom {}
     );
+    validate_platform_fee(&params)?;
 
     SUDO_PARAMS.save(deps.storage, &params)?;
 
     Ok(Response::new())
 }
 
+// The platform fee comes out of what the mint fee leaves to the seller
+fn validate_platform_fee(params: &OpenEditionMinterParams) -> Result<(), ContractError> {
+    let mint_fee_bps = params
+        .mint_fee_bps
+        .max(params.extension.verified_mint_fee_bps.unwrap_or_default());
+    let platform_fee_bps = params.extension.platform_fee_bps.unwrap_or_default();
+    ensure!(
+        mint_fee_bps.saturating_add(platform_fee_bps) <= 10_000,
+        ContractError::InvalidPlatformFeeBps {}
+    );
+    Ok(())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -81,13 +105,29 @@ pub fn execute_create_minter(
         &params,
     )?;
 
+    if let Some(platform_fee_address) = &params.extension.platform_fee_address {
+        deps.api.addr_validate(platform_fee_address)?;
+    }
+
+    let denom = &msg.init_msg.mint_price.denom;
+    let allowed_denoms = &params.extension.allowed_denoms;
     ensure!(
-        params.min_mint_price.denom == msg.init_msg.mint_price.denom,
+        allowed_denoms.is_empty() || allowed_denoms.contains(denom),
+        ContractError::DenomNotAllowed {
+            denom: denom.to_string()
+        }
+    );
+
+    ensure!(
+        params.min_mint_price.denom == *denom || allowed_denoms.contains(denom),
         BaseContractError::InvalidDenom {}
     );
 
+    // The min mint price has no meaning in another denom
     ensure!(
-        params.min_mint_price.amount <= msg.init_msg.mint_price.amount,
+        msg.init_msg.free_mint.unwrap_or_default()
+            || params.min_mint_price.denom != *denom
+            || params.min_mint_price.amount <= msg.init_msg.mint_price.amount,
         ContractError::InsufficientMintPrice {
             expected: params.min_mint_price.amount.u128(),
             got: msg.init_msg.mint_price.amount.into(),
@@ -104,7 +144,27 @@ pub fn execute_create_minter(
 
     Ok(res
         .add_attribute("action", "create_minter")
-        .add_message(wasm_msg))
+        .add_submessage(SubMsg::reply_on_success(
+            wasm_msg,
+            INSTANTIATE_MINTER_REPLY_ID,
+        )))
+}
+
+// Reply callback triggered from the minter instantiation, records the new minter
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id != INSTANTIATE_MINTER_REPLY_ID {
+        return Err(ContractError::InvalidReplyID {});
+    }
+
+    let res =
+        parse_reply_instantiate_data(msg).map_err(|_| ContractError::InstantiateMinterError {})?;
+    let minter = deps.api.addr_validate(&res.contract_address)?;
+    MINTERS.save(deps.storage, &minter, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate_minter_reply")
+        .add_attribute("minter", minter))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -149,21 +209,53 @@ pub fn sudo_update_params(
         .max_per_address_limit
         .unwrap_or(params.extension.max_per_address_limit);
 
+    params.extension.max_batch_mint_size = param_msg
+        .extension
+        .max_batch_mint_size
+        .unwrap_or(params.extension.max_batch_mint_size);
+
+    params.extension.allowed_denoms = param_msg
+        .extension
+        .allowed_denoms
+        .unwrap_or(params.extension.allowed_denoms);
+
+    if let Some(verified_mint_fee_bps) = param_msg.extension.verified_mint_fee_bps {
+        params.extension.verified_mint_fee_bps = Some(verified_mint_fee_bps);
+    }
+
+    if let Some(platform_fee_address) = param_msg.extension.platform_fee_address {
+        deps.api.addr_validate(&platform_fee_address)?;
+        params.extension.platform_fee_address = Some(platform_fee_address);
+    }
+    match param_msg.extension.platform_fee_bps {
+        Some(0) => {
+            params.extension.platform_fee_bps = None;
+            params.extension.platform_fee_address = None;
+        }
+        Some(platform_fee_bps) => params.extension.platform_fee_bps = Some(platform_fee_bps),
+        None => {}
+    }
+    validate_platform_fee(&params)?;
+
     SUDO_PARAMS.save(deps.storage, &params)?;
 
     Ok(Response::new().add_attribute("action", "sudo_update_params"))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: Sg2QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        Sg2QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
-        Sg2QueryMsg::AllowedCollectionCodeIds {} => {
+        QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::AllowedCollectionCodeIds {} => {
             to_json_binary(&query_allowed_collection_code_ids(deps)?)
         }
-        Sg2QueryMsg::AllowedCollectionCodeId(code_id) => {
+        QueryMsg::AllowedCollectionCodeId(code_id) => {
             to_json_binary(&query_allowed_collection_code_id(deps, code_id)?)
         }
+        QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
+        QueryMsg::StatusBatch { minters } => to_json_binary(&query_status_batch(deps, minters)?),
     }
 }
 
@@ -187,3 +279,40 @@ fn query_allowed_collection_code_id(
     let allowed = code_ids.contains(&code_id);
     Ok(AllowedCollectionCodeIdResponse { allowed })
 }
+
+fn query_minters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MintersResponse> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(deps.api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let minters = MINTERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    Ok(MintersResponse { minters })
+}
+
+// Any minter answering the sg4 status query works, not only the ones created here
+fn query_status_batch(deps: Deps, minters: Vec<String>) -> StdResult<StatusBatchResponse> {
+    if minters.len() > MAX_STATUS_BATCH_SIZE {
+        return Err(StdError::generic_err(format!(
+            "Status batch is limited to {MAX_STATUS_BATCH_SIZE} minters"
+        )));
+    }
+    let statuses = minters
+        .iter()
+        .map(|minter| {
+            let minter = deps.api.addr_validate(minter)?;
+            let status: StatusResponse = deps
+                .querier
+                .query_wasm_smart(&minter, &MinterQueryMsg::Status {})?;
+            Ok((minter, status))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(StatusBatchResponse { statuses })
+}
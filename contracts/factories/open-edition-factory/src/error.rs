@@ -31,6 +31,18 @@ pub enum ContractError {
     #[error("InvalidMintPrice")]
     InvalidMintPrice {},
 
+    #[error("InvalidReferralBps")]
+    InvalidReferralBps {},
+
+    #[error("ExtensionPoolTooSmall")]
+    ExtensionPoolTooSmall {},
+
+    #[error("InvalidPlatformFeeBps")]
+    InvalidPlatformFeeBps {},
+
+    #[error("DenomNotAllowed: {denom}")]
+    DenomNotAllowed { denom: String },
+
     #[error("InvalidNftDataProvided")]
     InvalidNftDataProvided {},
 
@@ -52,6 +64,12 @@ pub enum ContractError {
     #[error("Minimum network mint price {expected} got {got}")]
     InsufficientMintPrice { expected: u128, got: u128 },
 
+    #[error("Invalid reply ID")]
+    InvalidReplyID {},
+
+    #[error("Instantiate minter error")]
+    InstantiateMinterError {},
+
     #[error("{0}")]
     BaseError(#[from] BaseContractError),
 }
@@ -1,10 +1,14 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Deps, Env, Timestamp};
+use cosmwasm_std::{Addr, Coin, Decimal, Deps, Env, Timestamp, Uint128};
+use cw20::Cw20Coin;
 
 use sg2::msg::{CreateMinterMsg, Sg2ExecuteMsg, UpdateMinterParamsMsg};
+use sg2::CodeId;
+use sg4::StatusResponse;
+use sg_metadata::Metadata;
 
 use crate::state::OpenEditionMinterParams;
-use crate::types::NftData;
+use crate::types::{NftData, NftMetadataType, TradingStartBase};
 use crate::ContractError;
 
 #[cw_serde]
@@ -22,6 +26,48 @@ pub struct OpenEditionMinterInitMsgExtension {
     pub num_tokens: Option<u32>,
     // If not the admin/init
     pub payment_address: Option<String>,
+    /// Hard cap on mints per address, regardless of later per address limit updates
+    pub lifetime_per_address_cap: Option<u32>,
+    /// Optional split of the seller amount, shares must sum to 1. Takes precedence over payment_address
+    pub payment_splits: Option<Vec<(String, Decimal)>>,
+    /// Price charged on allowlist mints before start_time, defaults to mint_price
+    pub allowlist_mint_price: Option<Coin>,
+    /// Maximum number of tokens the admin can airdrop
+    pub max_airdrop_tokens: Option<u32>,
+    /// Share of the seller amount paid to referrers, in basis points
+    pub referral_bps: Option<u64>,
+    /// Seconds after the end time before anyone can purge
+    pub purge_delay_secs: Option<u64>,
+    /// Block height the public mint opens at, on top of the start time
+    pub start_height: Option<u64>,
+    /// On-chain metadata cycled through by sequential mints, overrides the nft_data extension
+    pub extension_pool: Option<Vec<Metadata>>,
+    /// Seed shuffling the order extension_pool entries are assigned in, disclosed once the mint ends
+    pub shuffle_seed: Option<u64>,
+    /// Holders of these collections can claim one free mint each
+    pub allowed_holder_collections: Option<Vec<String>>,
+    /// Prepended to minted token ids as `<prefix>-<index>`, letters, digits and dashes only
+    pub token_id_prefix: Option<String>,
+    /// Contract receiving the seller amount instead of `payment_address`, e.g. a splits contract
+    pub proceeds_contract: Option<String>,
+    /// Receives the dev share of the mint fee instead of the factory's dev fee address
+    pub dev_fee_address_override: Option<String>,
+    /// Maximum number of tokens a single MintBatch can mint, on top of the per address limit
+    pub per_tx_limit: Option<u32>,
+    /// Price of a public mint in a cw20 token, paid through the cw20 `Send` hook instead of native funds
+    pub cw20_mint_price: Option<Cw20Coin>,
+    /// Contract queried for the random seed of each on-chain metadata mint, a hash of the block time and token id otherwise
+    pub randomness_provider: Option<String>,
+    /// Time the default start trading time is derived from, start_time when unset
+    pub trading_start_base: Option<TradingStartBase>,
+    /// Lets the admin keep airdropping with mint_to after end_time, false by default
+    pub allow_airdrop_after_end: Option<bool>,
+    /// Free public mint, the mint price is exempt from the factory's min_mint_price and can be zero
+    pub free_mint: Option<bool>,
+    /// Tokens the admin has to mint with MintTo before anyone else can mint
+    pub reserved_tokens: Option<u32>,
+    /// At most N public mints per address within any window of M seconds, on top of the per address limit
+    pub rate_limit: Option<(u32, u64)>,
 }
 
 impl OpenEditionMinterInitMsgExtension {
@@ -34,6 +80,27 @@ impl OpenEditionMinterInitMsgExtension {
         // Validation of the Minter Params -> need to be in-line with the factory
         init_msg.nft_data = NftData::validate(init_msg.nft_data)?;
 
+        // A metadata pool only makes sense for on-chain metadata
+        if init_msg
+            .extension_pool
+            .as_ref()
+            .is_some_and(|pool| !pool.is_empty())
+            && init_msg.nft_data.nft_data_type != NftMetadataType::OnChainMetadata
+        {
+            return Err(ContractError::InvalidNftDataProvided {});
+        }
+
+        // A shuffled pool assigns each entry at most once, so it needs one entry per token
+        if init_msg.shuffle_seed.is_some() {
+            let pool_len = init_msg
+                .extension_pool
+                .as_ref()
+                .map_or(0, |pool| pool.len());
+            if pool_len < init_msg.num_tokens.unwrap_or(1) as usize {
+                return Err(ContractError::ExtensionPoolTooSmall {});
+            }
+        }
+
         // Optional: can have a max mint amount
         if let Some(max_num_tokens) = init_msg.num_tokens {
             if max_num_tokens == 0 || max_num_tokens > params.extension.max_token_limit {
@@ -75,10 +142,30 @@ impl OpenEditionMinterInitMsgExtension {
             return Err(ContractError::LimitOfTimeOrNumTokensRequired {});
         }
 
-        if init_msg.mint_price.amount < params.min_mint_price.amount {
+        // Free mints opt out of the price floor, which only applies in its own denom
+        let min_mint_price = if init_msg.free_mint.unwrap_or_default()
+            || init_msg.mint_price.denom != params.min_mint_price.denom
+        {
+            Uint128::zero()
+        } else {
+            params.min_mint_price.amount
+        };
+        if init_msg.mint_price.amount < min_mint_price {
             return Err(ContractError::InvalidMintPrice {});
         }
 
+        if let Some(allowlist_mint_price) = &init_msg.allowlist_mint_price {
+            if allowlist_mint_price.denom != init_msg.mint_price.denom
+                || allowlist_mint_price.amount < min_mint_price
+            {
+                return Err(ContractError::InvalidMintPrice {});
+            }
+        }
+
+        if init_msg.referral_bps.unwrap_or_default() > 10_000 {
+            return Err(ContractError::InvalidReferralBps {});
+        }
+
         Ok(OpenEditionMinterInitMsgExtension {
             nft_data: init_msg.nft_data,
             start_time: init_msg.start_time,
@@ -87,6 +174,27 @@ impl OpenEditionMinterInitMsgExtension {
             per_address_limit,
             num_tokens: init_msg.num_tokens,
             payment_address: init_msg.payment_address,
+            lifetime_per_address_cap: init_msg.lifetime_per_address_cap,
+            payment_splits: init_msg.payment_splits,
+            allowlist_mint_price: init_msg.allowlist_mint_price,
+            max_airdrop_tokens: init_msg.max_airdrop_tokens,
+            referral_bps: init_msg.referral_bps,
+            purge_delay_secs: init_msg.purge_delay_secs,
+            start_height: init_msg.start_height,
+            extension_pool: init_msg.extension_pool,
+            shuffle_seed: init_msg.shuffle_seed,
+            allowed_holder_collections: init_msg.allowed_holder_collections,
+            token_id_prefix: init_msg.token_id_prefix,
+            proceeds_contract: init_msg.proceeds_contract,
+            dev_fee_address_override: init_msg.dev_fee_address_override,
+            per_tx_limit: init_msg.per_tx_limit,
+            cw20_mint_price: init_msg.cw20_mint_price,
+            randomness_provider: init_msg.randomness_provider,
+            trading_start_base: init_msg.trading_start_base,
+            allow_airdrop_after_end: init_msg.allow_airdrop_after_end,
+            free_mint: init_msg.free_mint,
+            reserved_tokens: init_msg.reserved_tokens,
+            rate_limit: init_msg.rate_limit,
         })
     }
 }
@@ -109,10 +217,45 @@ pub struct OpenEditionUpdateParamsExtension {
     pub airdrop_mint_fee_bps: Option<u64>,
     pub airdrop_mint_price: Option<Coin>,
     pub dev_fee_address: Option<String>,
+    pub max_batch_mint_size: Option<u32>,
+    pub allowed_denoms: Option<Vec<String>>,
+    pub verified_mint_fee_bps: Option<u64>,
+    /// 0 removes the platform fee along with its address
+    pub platform_fee_bps: Option<u64>,
+    pub platform_fee_address: Option<String>,
 }
 pub type OpenEditionUpdateParamsMsg = UpdateMinterParamsMsg<OpenEditionUpdateParamsExtension>;
 
+/// `Sg2QueryMsg` along with the open edition specific queries
+#[cw_serde]
+pub enum QueryMsg {
+    /// Returns `ParamsResponse`
+    Params {},
+    AllowedCollectionCodeIds {},
+    AllowedCollectionCodeId(CodeId),
+    /// Minters created by this factory, ordered by address. Only covers minters created since
+    /// the factory started recording them, earlier minters are not listed
+    Minters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Status of each given minter, at most 50 per query
+    StatusBatch {
+        minters: Vec<String>,
+    },
+}
+
 #[cw_serde]
 pub struct ParamsResponse {
     pub params: OpenEditionMinterParams,
 }
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct StatusBatchResponse {
+    pub statuses: Vec<(Addr, StatusResponse)>,
+}
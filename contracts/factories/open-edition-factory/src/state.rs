@@ -1,9 +1,16 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw_storage_plus::{Item, Map};
 
 use sg2::MinterParams;
 
+/// Batch size applied to params stored before `max_batch_mint_size` existed
+pub const DEFAULT_MAX_BATCH_MINT_SIZE: u32 = 50;
+
+fn default_max_batch_mint_size() -> u32 {
+    DEFAULT_MAX_BATCH_MINT_SIZE
+}
+
 #[cw_serde]
 pub struct ParamsExtension {
     pub max_token_limit: u32,
@@ -11,7 +18,23 @@ pub struct ParamsExtension {
     pub airdrop_mint_fee_bps: u64,
     pub airdrop_mint_price: Coin,
     pub dev_fee_address: String,
+    /// Maximum number of tokens minted in a single batch mint
+    #[serde(default = "default_max_batch_mint_size")]
+    pub max_batch_mint_size: u32,
+    /// Denoms minters may charge in, empty only allows the min mint price denom.
+    /// The min mint price applies in its own denom only
+    #[serde(default)]
+    pub allowed_denoms: Vec<String>,
+    /// Share of each mint sent to the platform, taken from the seller amount
+    pub platform_fee_bps: Option<u64>,
+    pub platform_fee_address: Option<String>,
+    /// Mint fee charged once the minter is verified, defaults to `mint_fee_bps`
+    pub verified_mint_fee_bps: Option<u64>,
 }
 pub type OpenEditionMinterParams = MinterParams<ParamsExtension>;
 
 pub const SUDO_PARAMS: Item<OpenEditionMinterParams> = Item::new("sudo-params");
+
+/// Minters instantiated through `CreateMinter`, recorded once their instantiation succeeds.
+/// Minters created before this map existed are not backfilled, the factory never stored them
+pub const MINTERS: Map<&Addr, Empty> = Map::new("minters");
@@ -13,6 +13,15 @@ pub enum NftMetadataType {
     OffChainMetadata,
 }
 
+/// Base of the start trading time derived when the collection doesn't set one
+#[cw_serde]
+pub enum TradingStartBase {
+    /// `start_time + max_trading_offset_secs`
+    StartBased,
+    /// `end_time + offset_secs`, requires an end time
+    EndBased { offset_secs: u64 },
+}
+
 #[cw_serde]
 pub struct NftData {
     pub nft_data_type: NftMetadataType,
@@ -4,8 +4,13 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use open_edition_minter::msg::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MintCountResponse, MintPriceResponse,
-    MintableNumTokensResponse, QueryMsg, StartTimeResponse,
+    AirdropFeeResponse, AirdropQueueResponse, AllowlistAllocationResponse, CanMintResponse,
+    CollectionResponse, ConfigResponse, ContractInfoResponse, CurrentTokenIndexResponse,
+    ExecuteMsg, FeeScheduleResponse, FullStatusResponse, InstantiateMsg, MigrateMsg,
+    MintCountResponse, MintPriceResponse, MintableNumTokensResponse, NextTokenMetadataResponse,
+    QueryMsg, QuoteBatchResponse, ReceiveMsg, ResolvedConfigResponse, RevenueResponse,
+    ShuffleSeedResponse, SimulateMintResponse, StartTimeResponse, SudoMsg, SummaryResponse,
+    TimeRemainingResponse, TokenSeedResponse, UnlimitedAddressesResponse,
 };
 use open_edition_minter::state::Config;
 use sg4::StatusResponse;
@@ -18,12 +23,34 @@ fn main() {
 
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    export_schema(&schema_for!(ReceiveMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(SudoMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
     export_schema(&schema_for!(Config), &out_dir);
     export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(ResolvedConfigResponse), &out_dir);
     export_schema(&schema_for!(MintableNumTokensResponse), &out_dir);
     export_schema(&schema_for!(MintCountResponse), &out_dir);
     export_schema(&schema_for!(StartTimeResponse), &out_dir);
     export_schema(&schema_for!(MintPriceResponse), &out_dir);
     export_schema(&schema_for!(StatusResponse), &out_dir);
+    export_schema(&schema_for!(RevenueResponse), &out_dir);
+    export_schema(&schema_for!(FullStatusResponse), &out_dir);
+    export_schema(&schema_for!(AllowlistAllocationResponse), &out_dir);
+    export_schema(&schema_for!(CollectionResponse), &out_dir);
+    export_schema(&schema_for!(QuoteBatchResponse), &out_dir);
+    export_schema(&schema_for!(TimeRemainingResponse), &out_dir);
+    export_schema(&schema_for!(ShuffleSeedResponse), &out_dir);
+    export_schema(&schema_for!(SimulateMintResponse), &out_dir);
+    export_schema(&schema_for!(FeeScheduleResponse), &out_dir);
+    export_schema(&schema_for!(CurrentTokenIndexResponse), &out_dir);
+    export_schema(&schema_for!(UnlimitedAddressesResponse), &out_dir);
+    export_schema(&schema_for!(SummaryResponse), &out_dir);
+    export_schema(&schema_for!(NextTokenMetadataResponse), &out_dir);
+    export_schema(&schema_for!(TokenSeedResponse), &out_dir);
+    export_schema(&schema_for!(AirdropQueueResponse), &out_dir);
+    export_schema(&schema_for!(AirdropFeeResponse), &out_dir);
+    export_schema(&schema_for!(CanMintResponse), &out_dir);
+    export_schema(&schema_for!(ContractInfoResponse), &out_dir);
 }
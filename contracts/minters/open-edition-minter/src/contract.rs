@@ -1,37 +1,95 @@
 use crate::error::ContractError;
-use crate::helpers::mint_nft_msg;
+use crate::helpers::{apply_fee, fallback_token_seed, mint_nft_msg, prefixed_token_id};
 use crate::msg::{
-    ConfigResponse, EndTimeResponse, ExecuteMsg, MintCountResponse, MintPriceResponse,
-    MintableNumTokensResponse, QueryMsg, StartTimeResponse, TotalMintCountResponse,
+    AirdropFeeResponse, AirdropQueueResponse, AllowlistAllocationResponse, CanMintResponse,
+    CollectionResponse, ConfigResponse, ContractInfoResponse, CurrentTokenIndexResponse,
+    EndTimeResponse, ExecuteMsg, FeeScheduleResponse, FullStatusResponse, MigrateMsg,
+    MintCountResponse, MintPriceResponse, MintableNumTokensResponse, NextTokenMetadataResponse,
+    QueryMsg, QuoteBatchResponse, RandomSeedResponse, RandomnessProviderQueryMsg, ReceiveMsg,
+    ResolvedConfigResponse, RevenueResponse, ShuffleSeedResponse, SimulateMintResponse,
+    StartTimeResponse, SudoMsg, SummaryResponse, TimeRemainingResponse, TokenSeedResponse,
+    TotalMintCountResponse, UniqueMinterCheckpoint, UniqueMinterCurveResponse,
+    UnlimitedAddressesResponse,
 };
 use crate::state::{
-    increment_token_index, Config, ConfigExtension, CONFIG, MINTABLE_NUM_TOKENS, MINTER_ADDRS,
-    SG721_ADDRESS, STATUS, TOTAL_MINT_COUNT,
+    increment_token_index, record_revenue, record_unique_minter, Config, ConfigExtension,
+    AIRDROP_COUNT, AIRDROP_FEE_SPENT, AIRDROP_QUEUE, AIRDROP_QUEUE_REMAINING, AIRDROP_QUEUE_SEQ,
+    ALLOWLIST, CONFIG, CONFIG_FROZEN, HOLDER_CLAIMED, MAX_AIRDROP_FEE_SPEND, MINTABLE_NUM_TOKENS,
+    MINTER_ADDRS, MINT_CANCELLED, MINT_FORCE_ENDED, PER_ADDRESS_LIMIT_OVERRIDDEN, RECENT_MINTS,
+    SG721_ADDRESS, STATUS, TOKEN_INDEX, TOKEN_SEEDS, TOTAL_MINT_COUNT, TOTAL_NETWORK_FEES,
+    TOTAL_SELLER_REVENUE, UNIQUE_MINTER_CHECKPOINTS, UNIQUE_MINTER_COUNT,
+};
+use crate::validation::{
+    ensure_batch_len, normalize_ipfs_uri, sg721_label, validate_collection_description,
+    validate_collection_name, validate_payment_splits, validate_royalty_info,
+    validate_token_id_prefix,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, Event,
-    MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, Timestamp, WasmMsg,
+    coin, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Empty,
+    Env, Event, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, Storage, SubMsg,
+    Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw20::{
+    Cw20Coin, Cw20CoinVerified, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg, TokenInfoResponse,
+};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_storage_plus::{Bound, Map};
 use cw_utils::{may_pay, maybe_addr, nonpayable, parse_reply_instantiate_data};
 use open_edition_factory::msg::{OpenEditionMinterCreateMsg, ParamsResponse};
-use open_edition_factory::types::NftMetadataType;
+use open_edition_factory::state::OpenEditionMinterParams;
+use open_edition_factory::types::{NftData, NftMetadataType, TradingStartBase};
 use semver::Version;
 use sg1::{checked_fair_burn, ibc_denom_fair_burn};
-use sg2::query::Sg2QueryMsg;
-use sg4::{Status, StatusResponse, SudoMsg};
-use sg721::{ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
+use sg2::query::{AllowedCollectionCodeIdResponse, Sg2QueryMsg};
+use sg4::{Status, StatusResponse};
+use sg721::{
+    ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg, UpdateCollectionInfoMsg,
+};
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use sg_metadata::{Metadata, Trait};
 use sg_std::NATIVE_DENOM;
+use sg_whitelist::msg::{
+    ConfigResponse as WhitelistConfigResponse, HasMemberResponse, QueryMsg as WhitelistQueryMsg,
+};
 use url::Url;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:sg-open-edition-minter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+// features reported by the ContractInfo query
+pub const FEATURES: &[&str] = &[
+    "batch_mint",
+    "best_effort_batch_mint",
+    "allowlist",
+    "whitelist",
+    "holder_mint",
+    "referral_mint",
+    "cw20_mint",
+    "airdrop_queue",
+    "weighted_airdrop",
+    "free_mint",
+    "team_reserve",
+    "collection_migration",
+];
 
 const INSTANTIATE_SG721_REPLY_ID: u64 = 1;
 
+// Tolerated delay between the requested start time and the instantiation block
+const START_TIME_GRACE_SECS: u64 = 60;
+
+// Trait type of the random seed embedded in on-chain metadata
+const SEED_TRAIT_TYPE: &str = "seed";
+
+// cw2 name of the collection contract storing on-chain `Metadata`
+const ON_CHAIN_METADATA_COLLECTION: &str = "crates.io:sg721-metadata-onchain";
+
+const PAGINATION_DEFAULT_LIMIT: u32 = 25;
+const PAGINATION_MAX_LIMIT: u32 = 100;
+const MAX_MINT_COUNTS_ADDRESSES: usize = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -50,6 +108,72 @@ pub fn instantiate(
         .query_wasm_smart(factory.clone(), &Sg2QueryMsg::Params {})?;
     let factory_params = factory_response.params;
 
+    // Mints are charged in the denom of the factory's min mint price or one it allows
+    let denom = &msg.init_msg.mint_price.denom;
+    if *denom != factory_params.min_mint_price.denom
+        && !factory_params.extension.allowed_denoms.contains(denom)
+    {
+        return Err(ContractError::InvalidDenom {
+            expected: factory_params.min_mint_price.denom,
+            got: msg.init_msg.mint_price.denom,
+        });
+    }
+
+    validate_collection_name(&msg.collection_params.name)?;
+    validate_royalty_info(msg.collection_params.info.royalty_info.as_ref())?;
+    if let Some(prefix) = &msg.init_msg.token_id_prefix {
+        validate_token_id_prefix(prefix)?;
+    }
+    if msg.init_msg.per_tx_limit == Some(0) {
+        return Err(ContractError::InvalidPerTxLimit {});
+    }
+    // A reserve the admin can't mint would keep the public mint closed forever
+    let reserved_tokens = msg.init_msg.reserved_tokens.unwrap_or_default();
+    if msg
+        .init_msg
+        .num_tokens
+        .is_some_and(|num_tokens| reserved_tokens > num_tokens)
+        || msg
+            .init_msg
+            .max_airdrop_tokens
+            .is_some_and(|max_airdrop_tokens| reserved_tokens > max_airdrop_tokens)
+    {
+        return Err(ContractError::InvalidReservedTokens {});
+    }
+    if let Some((max_mints, window_secs)) = msg.init_msg.rate_limit {
+        if max_mints == 0 || window_secs == 0 {
+            return Err(ContractError::InvalidRateLimit {});
+        }
+    }
+    let proceeds_contract = maybe_addr(deps.api, msg.init_msg.proceeds_contract)?;
+    if let Some(proceeds_contract) = &proceeds_contract {
+        deps.querier
+            .query_wasm_contract_info(proceeds_contract)
+            .map_err(|_| ContractError::InvalidProceedsContract {})?;
+    }
+    let dev_fee_address_override = maybe_addr(deps.api, msg.init_msg.dev_fee_address_override)?;
+    let cw20_mint_price = msg
+        .init_msg
+        .cw20_mint_price
+        .map(|price| validate_cw20_mint_price(deps.as_ref(), price))
+        .transpose()?;
+
+    // The factory validates times too, double check so a minter can't be created unusable
+    if msg.init_msg.start_time.plus_seconds(START_TIME_GRACE_SECS) < env.block.time {
+        return Err(ContractError::InvalidStartTime(
+            msg.init_msg.start_time,
+            env.block.time,
+        ));
+    }
+    if let Some(end_time) = msg.init_msg.end_time {
+        if end_time <= msg.init_msg.start_time {
+            return Err(ContractError::InvalidEndTime(
+                end_time,
+                msg.init_msg.start_time,
+            ));
+        }
+    }
+
     // set default status so it can be queried without failing
     STATUS.save(deps.storage, &Status::default())?;
 
@@ -63,8 +187,8 @@ pub fn instantiate(
                 .as_ref()
                 .map(|uri| uri.trim().to_string())
                 .map_or_else(|| Err(ContractError::InvalidBaseTokenURI {}), Ok)?;
-            // Token URI must be a valid URL (ipfs, https, etc.)
-            Url::parse(&base_token_uri).map_err(|_| ContractError::InvalidBaseTokenURI {})?;
+            // Token URI must be a valid URL (ipfs, https, etc.), IPFS gateways are made canonical
+            let base_token_uri = normalize_ipfs_uri(&base_token_uri)?;
             msg.init_msg.nft_data.token_uri = Some(base_token_uri);
         }
         // If on-chain metadata -> make sure that the image data is a valid URL
@@ -81,6 +205,14 @@ pub fn instantiate(
             if let Some(ext) = msg.init_msg.nft_data.extension.as_mut() {
                 ext.image = base_img_url;
             }
+            for ext in msg.init_msg.extension_pool.iter_mut().flatten() {
+                ext.image = ext
+                    .image
+                    .as_ref()
+                    .map(|img| Url::parse(img.trim()))
+                    .transpose()?
+                    .map(|url| url.to_string());
+            }
         }
     }
 
@@ -100,12 +232,37 @@ pub fn instantiate(
             ));
         }
     }
+    let derived_start_trading_time = match msg.init_msg.trading_start_base {
+        None | Some(TradingStartBase::StartBased) => default_start_time_with_offset,
+        Some(TradingStartBase::EndBased { offset_secs }) => {
+            let end_time = msg
+                .init_msg
+                .end_time
+                .ok_or(ContractError::EndBasedTradingWithoutEndTime {})?;
+            // The offset from the end time is capped like the one from the start time
+            if offset_secs > offset {
+                return Err(ContractError::InvalidStartTradingTime(
+                    end_time.plus_seconds(offset_secs),
+                    end_time.plus_seconds(offset),
+                ));
+            }
+            end_time.plus_seconds(offset_secs)
+        }
+    };
     let start_trading_time = msg
         .collection_params
         .info
         .start_trading_time
-        .or(Some(default_start_time_with_offset));
+        .or(Some(derived_start_trading_time));
     collection_info.start_trading_time = start_trading_time;
+    // The collection caps its supply at the edition size too, should the minter ever overmint
+    if let Some(num_tokens) = msg.init_msg.num_tokens {
+        collection_info.max_supply = Some(
+            collection_info
+                .max_supply
+                .map_or(num_tokens, |max_supply| max_supply.min(num_tokens)),
+        );
+    }
 
     let config = Config {
         factory: factory.clone(),
@@ -120,6 +277,41 @@ pub fn instantiate(
             end_time: msg.init_msg.end_time,
             nft_data: msg.init_msg.nft_data,
             num_tokens: msg.init_msg.num_tokens,
+            lifetime_per_address_cap: msg.init_msg.lifetime_per_address_cap,
+            payment_splits: msg
+                .init_msg
+                .payment_splits
+                .map(|splits| validate_payment_splits(deps.api, splits))
+                .transpose()?,
+            allowlist_mint_price: msg.init_msg.allowlist_mint_price,
+            max_airdrop_tokens: msg.init_msg.max_airdrop_tokens,
+            referral_bps: msg.init_msg.referral_bps.unwrap_or_default(),
+            purge_delay_secs: msg.init_msg.purge_delay_secs.unwrap_or_default(),
+            start_height: msg.init_msg.start_height,
+            extension_pool: msg.init_msg.extension_pool.unwrap_or_default(),
+            shuffle_seed: msg.init_msg.shuffle_seed,
+            whitelist: None,
+            allowed_holder_collections: msg
+                .init_msg
+                .allowed_holder_collections
+                .map(|collections| {
+                    collections
+                        .iter()
+                        .map(|collection| deps.api.addr_validate(collection))
+                        .collect::<StdResult<Vec<_>>>()
+                })
+                .transpose()?,
+            token_id_prefix: msg.init_msg.token_id_prefix,
+            proceeds_contract,
+            unlimited_addresses: None,
+            dev_fee_address_override,
+            per_tx_limit: msg.init_msg.per_tx_limit,
+            cw20_mint_price,
+            randomness_provider: maybe_addr(deps.api, msg.init_msg.randomness_provider)?,
+            allow_airdrop_after_end: msg.init_msg.allow_airdrop_after_end.unwrap_or_default(),
+            free_mint: msg.init_msg.free_mint.unwrap_or_default(),
+            reserved_tokens,
+            rate_limit: msg.init_msg.rate_limit,
         },
         mint_price: msg.init_msg.mint_price,
     };
@@ -145,8 +337,9 @@ pub fn instantiate(
                 collection_info,
             })?,
             funds: info.funds,
-            admin: Some(config.extension.admin.to_string()),
-            label: format!("SG721-{}", msg.collection_params.name.trim()),
+            // The minter administers the collection so governance can migrate it
+            admin: Some(env.contract.address.to_string()),
+            label: sg721_label(&msg.collection_params.name),
         }
         .into(),
         id: INSTANTIATE_SG721_REPLY_ID,
@@ -170,24 +363,64 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint {} => execute_mint_sender(deps, env, info),
+        ExecuteMsg::Mint {} => execute_mint_sender(deps, env, info, None, None),
+        ExecuteMsg::MintWithReferral { referrer } => {
+            execute_mint_sender(deps, env, info, None, Some(referrer))
+        }
+        ExecuteMsg::MintFor { recipient } => {
+            execute_mint_sender(deps, env, info, Some(recipient), None)
+        }
+        ExecuteMsg::MintBatch { count, best_effort } => {
+            execute_mint_batch(deps, env, info, count, best_effort.unwrap_or_default())
+        }
         ExecuteMsg::Purge {} => execute_purge(deps, env, info),
         ExecuteMsg::UpdateMintPrice { price } => execute_update_mint_price(deps, env, info, price),
         ExecuteMsg::UpdateStartTime(time) => execute_update_start_time(deps, env, info, time),
         ExecuteMsg::UpdateEndTime(time) => execute_update_end_time(deps, env, info, time),
+        ExecuteMsg::ReopenMint { new_end_time } => {
+            execute_reopen_mint(deps, env, info, new_end_time)
+        }
         ExecuteMsg::UpdateStartTradingTime(time) => {
             execute_update_start_trading_time(deps, env, info, time)
         }
+        ExecuteMsg::UpdateCollectionInfo { description, image } => {
+            execute_update_collection_info(deps, info, description, image)
+        }
         ExecuteMsg::UpdatePerAddressLimit { per_address_limit } => {
             execute_update_per_address_limit(deps, env, info, per_address_limit)
         }
         ExecuteMsg::MintTo { recipient } => execute_mint_to(deps, env, info, recipient),
+        ExecuteMsg::MintToBatch { recipients } => {
+            execute_mint_to_batch(deps, env, info, recipients)
+        }
+        ExecuteMsg::MintToWeighted { recipients } => {
+            execute_mint_to_weighted(deps, env, info, recipients)
+        }
         ExecuteMsg::BurnRemaining {} => execute_burn_remaining(deps, env, info),
+        ExecuteMsg::SetAllowlist { entries } => execute_set_allowlist(deps, info, entries),
+        ExecuteMsg::MintAllowlist {} => execute_mint_allowlist(deps, env, info),
+        ExecuteMsg::SetWhitelist { address } => execute_set_whitelist(deps, env, info, address),
+        ExecuteMsg::MintWhitelist {} => execute_mint_whitelist(deps, env, info),
+        ExecuteMsg::MintAsHolder {} => execute_mint_as_holder(deps, env, info),
+        ExecuteMsg::FreezeConfig {} => execute_freeze_config(deps, info),
+        ExecuteMsg::SetUnlimitedAddresses { addresses } => {
+            execute_set_unlimited_addresses(deps, info, addresses)
+        }
+        ExecuteMsg::CancelMint {} => execute_cancel_mint(deps, env, info),
+        ExecuteMsg::QueueAirdrop { recipients } => {
+            execute_queue_airdrop(deps, env, info, recipients)
+        }
+        ExecuteMsg::ProcessAirdrop { limit } => execute_process_airdrop(deps, env, info, limit),
+        ExecuteMsg::DequeueAirdrop { limit } => execute_dequeue_airdrop(deps, info, limit),
+        ExecuteMsg::UpdateMaxAirdropFeeSpend {
+            max_airdrop_fee_spend,
+        } => execute_update_max_airdrop_fee_spend(deps, info, max_airdrop_fee_spend),
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
     }
 }
 
 // Purge frees data after a mint has ended
-// Anyone can purge
+// Anyone can purge once the purge delay has passed, the admin right after the end time
 pub fn execute_purge(
     deps: DepsMut,
     env: Env,
@@ -203,9 +436,15 @@ pub fn execute_purge(
     }
 
     // Check if mint has ended (optional)
-    let end_time = CONFIG.load(deps.storage)?.extension.end_time;
-    if let Some(end_time_u) = end_time {
-        if env.block.time <= end_time_u {
+    let config = CONFIG.load(deps.storage)?;
+    if let Some(end_time_u) = config.extension.end_time {
+        // Let indexers catch up on the minter data before it goes away
+        let purge_delay_secs = if info.sender == config.extension.admin {
+            0
+        } else {
+            config.extension.purge_delay_secs
+        };
+        if env.block.time <= end_time_u.plus_seconds(purge_delay_secs) {
             return Err(ContractError::MintingHasNotYetEnded {});
         }
     }
@@ -227,27 +466,286 @@ pub fn execute_mint_sender(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    recipient: Option<String>,
+    referrer: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let action = if recipient.is_some() {
+        "mint_for"
+    } else {
+        "mint_sender"
+    };
+    let recipient = maybe_addr(deps.api, recipient)?.unwrap_or_else(|| info.sender.clone());
+
+    let referrer = referrer
+        .map(|referrer| deps.api.addr_validate(&referrer))
+        .transpose()?;
+    if referrer.as_ref() == Some(&info.sender) {
+        return Err(ContractError::SelfReferral {});
+    }
+
+    check_public_mint_window(&config, &env)?;
+
+    // Check if already minted max per address limit, unless the sender is exempt
+    let limit_exempt = config
+        .extension
+        .unlimited_addresses
+        .iter()
+        .flatten()
+        .any(|addr| *addr == info.sender);
+    if !limit_exempt
+        && matches!(mint_count_per_addr(deps.as_ref(), &info)?, count if count >= config.extension.per_address_limit)
+    {
+        return Err(ContractError::MaxPerAddressLimitExceeded {});
+    }
+    record_rate_limited_mints(deps.storage, &env, &config, &info.sender, 1)?;
+
+    // The per address limit applies to the sender, whoever receives the token
+    let recipients = vec![recipient];
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = mint_price_with_params(&config, &factory_params, false);
+    let res = _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        false,
+        mint_price,
+        recipients,
+        referrer,
+        factory_params,
+    )?;
+    if limit_exempt {
+        return Ok(res.add_attribute("limit_exempt", "true"));
+    }
+    Ok(res)
+}
+
+// Gates of a public batch mint, shared with its quote
+fn check_mint_batch(
+    config: &Config,
+    factory_params: &OpenEditionMinterParams,
+    env: &Env,
+    mint_count: u32,
+    count: u32,
+) -> Result<(), ContractError> {
+    check_public_mint_window(config, env)?;
+
+    let max = factory_params.extension.max_batch_mint_size;
+    if count == 0 {
+        return Err(ContractError::InvalidNumTokens { max, min: 1 });
+    }
+    ensure_batch_len(count as usize)?;
+    if count > max {
+        return Err(ContractError::BatchTooLarge { max });
+    }
+    if let Some(max) = config.extension.per_tx_limit {
+        if count > max {
+            return Err(ContractError::PerTxLimitExceeded { max });
+        }
+    }
+
+    // The whole batch has to fit in the per address limit
+    if mint_count + count > config.extension.per_address_limit {
+        return Err(ContractError::MaxPerAddressLimitExceeded {});
+    }
+    Ok(())
+}
+
+pub fn execute_mint_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    count: u32,
+    best_effort: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let action = "mint_sender";
+    let action = "mint_batch";
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_count = mint_count_per_addr(deps.as_ref(), &info)?;
+    check_mint_batch(&config, &factory_params, &env, mint_count, count)?;
+
+    let mint_price = mint_price_with_params(&config, &factory_params, false);
+    if !best_effort {
+        record_rate_limited_mints(deps.storage, &env, &config, &info.sender, count)?;
+        let recipients = vec![info.sender.clone(); count as usize];
+        return _execute_mint(
+            deps,
+            env,
+            info,
+            action,
+            false,
+            mint_price,
+            recipients,
+            None,
+            factory_params,
+        );
+    }
+
+    // Best effort: the full batch is paid, what doesn't fit in the supply is refunded
+    let total_price = mint_price.amount.checked_mul(Uint128::from(count))?;
+    let payment = may_pay(&info, &mint_price.denom)?;
+    if payment != total_price {
+        return Err(ContractError::IncorrectPaymentAmount(
+            coin(payment.u128(), &mint_price.denom),
+            coin(total_price.u128(), &mint_price.denom),
+        ));
+    }
+    let minted_count = match MINTABLE_NUM_TOKENS.may_load(deps.storage)? {
+        Some(mintable_num_tokens) => count.min(mintable_num_tokens),
+        None => count,
+    };
+    if minted_count == 0 {
+        return Err(ContractError::SoldOut {});
+    }
+    record_rate_limited_mints(deps.storage, &env, &config, &info.sender, minted_count)?;
+    let minted_price = mint_price.amount.checked_mul(Uint128::from(minted_count))?;
+    let refund = total_price.checked_sub(minted_price)?;
+
+    let sender = info.sender.clone();
+    let minted_info = MessageInfo {
+        sender: info.sender,
+        funds: if minted_price.is_zero() {
+            vec![]
+        } else {
+            vec![coin(minted_price.u128(), &mint_price.denom)]
+        },
+    };
+    let recipients = vec![sender.clone(); minted_count as usize];
+    let denom = mint_price.denom.clone();
+    let mut res = _execute_mint(
+        deps,
+        env,
+        minted_info,
+        action,
+        false,
+        mint_price,
+        recipients,
+        None,
+        factory_params,
+    )?;
+    if !refund.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: vec![coin(refund.u128(), &denom)],
+        });
+    }
+    Ok(res
+        .add_attribute("minted_count", minted_count.to_string())
+        .add_attribute("refund", coin(refund.u128(), denom).to_string()))
+}
 
+// Start time, start height and end time gates shared by the public mints
+fn check_public_mint_window(config: &Config, env: &Env) -> Result<(), ContractError> {
     // Check start and end time (if not optional)
     if env.block.time < config.extension.start_time {
         return Err(ContractError::BeforeMintStartTime {});
     }
+    // Height gate makes the opening block deterministic
+    if let Some(start_height) = config.extension.start_height {
+        if env.block.height < start_height {
+            return Err(ContractError::BeforeMintStartTime {});
+        }
+    }
     if let Some(end_time) = config.extension.end_time {
         if env.block.time >= end_time {
             return Err(ContractError::AfterMintEndTime {});
         }
     }
+    Ok(())
+}
 
-    // Check if already minted max per address limit
-    if matches!(mint_count_per_addr(deps.as_ref(), &info)?, count if count >= config.extension.per_address_limit)
-    {
-        return Err(ContractError::MaxPerAddressLimitExceeded {});
+// The team reserve goes out through admin mints before anyone else can mint
+fn check_reserve_minted(storage: &dyn Storage, config: &Config) -> Result<(), ContractError> {
+    let airdrop_count = AIRDROP_COUNT.may_load(storage)?.unwrap_or_default();
+    if airdrop_count < config.extension.reserved_tokens {
+        return Err(ContractError::ReserveNotMinted {});
+    }
+    Ok(())
+}
+
+// Checks every mint goes through, native or cw20, public or admin. Returns the mintable supply.
+// Supply is checked before any fee or payment msg is built so a buyer racing for the last
+// tokens gets a clean SoldOut, admin mints are held to the same cap
+fn check_mint_gates(
+    storage: &dyn Storage,
+    config: &Config,
+    sender: &Addr,
+    mint_num: u32,
+    is_admin: bool,
+) -> Result<Option<u32>, ContractError> {
+    // Governance blocking applies to every mint entry point, admin included
+    if STATUS.load(storage)?.is_blocked {
+        return Err(ContractError::MinterBlocked {});
+    }
+    if MINT_CANCELLED.may_load(storage)?.unwrap_or_default() {
+        return Err(ContractError::MintCancelled {});
+    }
+    let mintable_num_tokens = MINTABLE_NUM_TOKENS.may_load(storage)?;
+    if mintable_num_tokens.is_some_and(|mintable_num_tokens| mintable_num_tokens < mint_num) {
+        return Err(ContractError::SoldOut {});
+    }
+    if !is_admin {
+        check_reserve_minted(storage, config)?;
+        // Lifetime cap applies on top of the per address limit, whatever it was updated to
+        if let Some(cap) = config.extension.lifetime_per_address_cap {
+            let mint_count = MINTER_ADDRS.may_load(storage, sender)?.unwrap_or_default();
+            if mint_count + mint_num > cap {
+                return Err(ContractError::LifetimeLimitExceeded {});
+            }
+        }
+    }
+    Ok(mintable_num_tokens)
+}
+
+// Mints of `sender` still inside the rate limit window, errors when `count` more don't fit in it
+fn check_rate_limit(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+    sender: &Addr,
+    count: u32,
+) -> Result<Vec<Timestamp>, ContractError> {
+    let Some((max_mints, window_secs)) = config.extension.rate_limit else {
+        return Ok(vec![]);
+    };
+    let recent_mints: Vec<Timestamp> = RECENT_MINTS
+        .may_load(storage, sender)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|mint_time| mint_time.plus_seconds(window_secs) > env.block.time)
+        .collect();
+    let total = recent_mints.len() as u32 + count;
+    if total > max_mints {
+        // The oldest mints have to leave the window to make room for `count` more
+        let retry_after = recent_mints
+            .get((total - max_mints - 1) as usize)
+            .or(recent_mints.last())
+            .map(|mint_time| {
+                (mint_time.plus_seconds(window_secs).nanos() - env.block.time.nanos())
+                    .div_ceil(1_000_000_000)
+            })
+            .unwrap_or(window_secs);
+        return Err(ContractError::RateLimited { retry_after });
     }
+    Ok(recent_mints)
+}
 
-    _execute_mint(deps, env, info, action, false, None)
+fn record_rate_limited_mints(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    sender: &Addr,
+    count: u32,
+) -> Result<(), ContractError> {
+    if config.extension.rate_limit.is_none() {
+        return Ok(());
+    }
+    let mut recent_mints = check_rate_limit(storage, env, config, sender, count)?;
+    recent_mints.extend(std::iter::repeat(env.block.time).take(count as usize));
+    RECENT_MINTS.save(storage, sender, &recent_mints)?;
+    Ok(())
 }
 
 pub fn execute_mint_to(
@@ -268,171 +766,83 @@ pub fn execute_mint_to(
     }
 
     if let Some(end_time) = config.extension.end_time {
-        if env.block.time >= end_time {
+        if env.block.time >= end_time && !config.extension.allow_airdrop_after_end {
             return Err(ContractError::AfterMintEndTime {});
         }
     }
 
-    _execute_mint(deps, env, info, action, true, Some(recipient))
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = mint_price_with_params(&config, &factory_params, true);
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        mint_price,
+        vec![recipient],
+        None,
+        factory_params,
+    )
 }
 
-// Generalize checks and mint message creation
-// mint -> _execute_mint(recipient: None, token_id: None)
-// mint_to(recipient: "friend") -> _execute_mint(Some(recipient), token_id: None)
-fn _execute_mint(
+pub fn execute_mint_to_batch(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    action: &str,
-    is_admin: bool,
-    recipient: Option<Addr>,
+    recipients: Vec<String>,
 ) -> Result<Response, ContractError> {
-    let mintable_num_tokens = MINTABLE_NUM_TOKENS.may_load(deps.storage)?;
-    if let Some(mintable_nb_tokens) = mintable_num_tokens {
-        if mintable_nb_tokens == 0 {
-            return Err(ContractError::SoldOut {});
-        }
-    }
     let config = CONFIG.load(deps.storage)?;
+    let action = "mint_to_batch";
 
-    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
-
-    let recipient_addr = match recipient {
-        Some(some_recipient) => some_recipient,
-        None => info.sender.clone(),
-    };
-
-    let mint_price: Coin = mint_price(deps.as_ref(), is_admin)?;
-    // Exact payment only accepted
-    let payment = may_pay(&info, &mint_price.denom)?;
-    if payment != mint_price.amount {
-        return Err(ContractError::IncorrectPaymentAmount(
-            coin(payment.u128(), &config.mint_price.denom),
-            mint_price,
+    // Check only admin
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
         ));
     }
 
-    let mut res = Response::new();
-
-    let factory: ParamsResponse = deps
-        .querier
-        .query_wasm_smart(config.factory, &Sg2QueryMsg::Params {})?;
-    let factory_params = factory.params;
-
-    // Create fee msgs
-    // Metadata Storage fees -> minting fee will be enabled for on-chain metadata mints
-    // dev fees are intrinsic in the mint fee (assuming a 50% share)
-    let mint_fee = if is_admin {
-        Decimal::bps(factory_params.extension.airdrop_mint_fee_bps)
-    } else {
-        Decimal::bps(factory_params.mint_fee_bps)
-    };
-    let network_fee = mint_price.amount * mint_fee;
-
-    // This is for the network fee msg
-    // send non-native fees to community pool
-    if mint_price.denom != NATIVE_DENOM {
-        // only send non-zero amounts
-        // send portion to dev addr
-        if !network_fee.is_zero() {
-            ibc_denom_fair_burn(
-                coin(network_fee.u128(), mint_price.denom.to_string()),
-                Some(
-                    deps.api
-                        .addr_validate(&factory_params.extension.dev_fee_address)?,
-                ),
-                &mut res,
-            )?;
+    if let Some(end_time) = config.extension.end_time {
+        if env.block.time >= end_time && !config.extension.allow_airdrop_after_end {
+            return Err(ContractError::AfterMintEndTime {});
         }
-    } else if !network_fee.is_zero() {
-        checked_fair_burn(
-            &info,
-            network_fee.u128(),
-            Some(
-                deps.api
-                    .addr_validate(&factory_params.extension.dev_fee_address)?,
-            ),
-            &mut res,
-        )?;
     }
 
-    // Token ID to mint + update the config counter
-    let token_id = increment_token_index(deps.storage)?.to_string();
-
-    // Create mint msg -> dependents on the NFT data type
-    let msg = mint_nft_msg(
-        sg721_address,
-        token_id.clone(),
-        recipient_addr.clone(),
-        match config.extension.nft_data.nft_data_type {
-            NftMetadataType::OnChainMetadata => config.extension.nft_data.extension,
-            NftMetadataType::OffChainMetadata => None,
-        },
-        match config.extension.nft_data.nft_data_type {
-            NftMetadataType::OnChainMetadata => None,
-            NftMetadataType::OffChainMetadata => config.extension.nft_data.token_uri,
-        },
-    )?;
-    res = res.add_message(msg);
-
-    // Save the new mint count for the sender's address
-    let new_mint_count = mint_count_per_addr(deps.as_ref(), &info)? + 1;
-    MINTER_ADDRS.save(deps.storage, &info.sender, &new_mint_count)?;
-
-    // Update the mint count
-    TOTAL_MINT_COUNT.update(
-        deps.storage,
-        |mut updated_mint_count| -> Result<_, ContractError> {
-            updated_mint_count += 1u32;
-            Ok(updated_mint_count)
-        },
-    )?;
-
-    // Update mintable count (optional)
-    if let Some(mintable_nb_tokens) = mintable_num_tokens {
-        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_nb_tokens - 1))?;
+    if recipients.is_empty() {
+        return Err(ContractError::EmptyRecipients {});
     }
-
-    let seller_amount = {
-        // the net amount is mint price - network fee (mint free + dev fee)
-        let amount = mint_price.amount.checked_sub(network_fee)?;
-        let payment_address = config.extension.payment_address;
-        let seller = config.extension.admin;
-        // Sending 0 coins fails, so only send if amount is non-zero
-        if !amount.is_zero() {
-            let msg = BankMsg::Send {
-                to_address: payment_address.unwrap_or(seller).to_string(),
-                amount: vec![coin(amount.u128(), mint_price.clone().denom)],
-            };
-            res = res.add_message(msg);
-        }
-        amount
-    };
-
-    Ok(res
-        .add_attribute("action", action)
-        .add_attribute("sender", info.sender)
-        .add_attribute("recipient", recipient_addr)
-        .add_attribute("token_id", token_id)
-        .add_attribute(
-            "network_fee",
-            coin(network_fee.into(), mint_price.clone().denom).to_string(),
-        )
-        .add_attribute("mint_price", mint_price.to_string())
-        .add_attribute(
-            "seller_amount",
-            coin(seller_amount.into(), mint_price.denom).to_string(),
-        ))
+    ensure_batch_len(recipients.len())?;
+    // Validate every address before minting anything
+    let recipients = recipients
+        .iter()
+        .map(|recipient| deps.api.addr_validate(recipient))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = mint_price_with_params(&config, &factory_params, true);
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        mint_price,
+        recipients,
+        None,
+        factory_params,
+    )
 }
 
-pub fn execute_update_mint_price(
+pub fn execute_mint_to_weighted(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    price: u128,
+    recipients: Vec<(String, u32)>,
 ) -> Result<Response, ContractError> {
-    nonpayable(&info)?;
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let action = "mint_to_weighted";
+
+    // Check only admin
     if info.sender != config.extension.admin {
         return Err(ContractError::Unauthorized(
             "Sender is not an admin".to_owned(),
@@ -440,73 +850,1118 @@ pub fn execute_update_mint_price(
     }
 
     if let Some(end_time) = config.extension.end_time {
-        if env.block.time >= end_time {
+        if env.block.time >= end_time && !config.extension.allow_airdrop_after_end {
             return Err(ContractError::AfterMintEndTime {});
         }
     }
 
-    // If current time is after the stored start_time, only allow lowering price
-    if env.block.time >= config.extension.start_time && price >= config.mint_price.amount.u128() {
-        return Err(ContractError::UpdatedMintPriceTooHigh {
-            allowed: config.mint_price.amount.u128(),
-            updated: price,
-        });
+    // Both the entries and the tokens they expand to count against the batch limit
+    ensure_batch_len(recipients.len())?;
+    // Enforce the token limit on the total before expanding the list
+    let minted_count = recipients
+        .iter()
+        .try_fold(0u32, |total, (_, count)| total.checked_add(*count))
+        .ok_or(ContractError::SoldOut {})?;
+    if minted_count == 0 {
+        return Err(ContractError::EmptyRecipients {});
+    }
+    ensure_batch_len(minted_count as usize)?;
+    if let Some(mintable_num_tokens) = MINTABLE_NUM_TOKENS.may_load(deps.storage)? {
+        if minted_count > mintable_num_tokens {
+            return Err(ContractError::SoldOut {});
+        }
     }
 
-    let factory: ParamsResponse = deps
-        .querier
-        .query_wasm_smart(config.clone().factory, &Sg2QueryMsg::Params {})?;
-    let factory_params = factory.params;
-
-    if factory_params.min_mint_price.amount.u128() > price {
-        return Err(ContractError::InsufficientMintPrice {
-            expected: factory_params.min_mint_price.amount.u128(),
-            got: price,
-        });
+    // Validate every address before minting anything, then flatten into one token per entry
+    let mut flat_recipients = vec![];
+    for (recipient, count) in recipients {
+        let recipient = deps.api.addr_validate(&recipient)?;
+        flat_recipients.extend(std::iter::repeat(recipient).take(count as usize));
     }
 
-    config.mint_price = coin(price, config.mint_price.denom);
-    CONFIG.save(deps.storage, &config)?;
-    Ok(Response::new()
-        .add_attribute("action", "update_mint_price")
-        .add_attribute("sender", info.sender)
-        .add_attribute("mint_price", config.mint_price.to_string()))
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = mint_price_with_params(&config, &factory_params, true);
+    let res = _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        true,
+        mint_price,
+        flat_recipients,
+        None,
+        factory_params,
+    )?;
+    Ok(res.add_attribute("minted_count", minted_count.to_string()))
 }
 
-pub fn execute_update_start_time(
+// The airdrop price is escrowed when queueing so anyone can process the queue later
+pub fn execute_queue_airdrop(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    start_time: Timestamp,
+    recipients: Vec<String>,
 ) -> Result<Response, ContractError> {
-    nonpayable(&info)?;
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Check only admin
     if info.sender != config.extension.admin {
         return Err(ContractError::Unauthorized(
             "Sender is not an admin".to_owned(),
         ));
     }
-    // If current time is after the stored start time return error
-    if env.block.time >= config.extension.start_time {
-        return Err(ContractError::AlreadyStarted {});
+
+    if let Some(end_time) = config.extension.end_time {
+        if env.block.time >= end_time && !config.extension.allow_airdrop_after_end {
+            return Err(ContractError::AfterMintEndTime {});
+        }
     }
 
-    // If current time already passed the new start_time return error
-    if env.block.time > start_time {
-        return Err(ContractError::InvalidStartTime(start_time, env.block.time));
+    if recipients.is_empty() {
+        return Err(ContractError::EmptyRecipients {});
+    }
+    ensure_batch_len(recipients.len())?;
+    let recipients = recipients
+        .iter()
+        .map(|recipient| deps.api.addr_validate(recipient))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = mint_price_with_params(&config, &factory_params, true);
+    let total_price = mint_price
+        .amount
+        .checked_mul(Uint128::from(recipients.len() as u128))?;
+    let payment = may_pay(&info, &mint_price.denom)?;
+    if payment != total_price {
+        return Err(ContractError::IncorrectPaymentAmount(
+            coin(payment.u128(), &mint_price.denom),
+            coin(total_price.u128(), &mint_price.denom),
+        ));
     }
 
-    // If the new start_time is after end_time return error
-    if let Some(end_time) = config.extension.end_time {
-        if start_time > end_time {
-            return Err(ContractError::InvalidStartTime(end_time, start_time));
-        }
+    let mut seq = AIRDROP_QUEUE_SEQ
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    for recipient in recipients.iter() {
+        AIRDROP_QUEUE.save(deps.storage, seq, &(recipient.clone(), mint_price.clone()))?;
+        seq += 1;
     }
+    AIRDROP_QUEUE_SEQ.save(deps.storage, &seq)?;
+    let remaining = AIRDROP_QUEUE_REMAINING
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + recipients.len() as u32;
+    AIRDROP_QUEUE_REMAINING.save(deps.storage, &remaining)?;
 
-    config.extension.start_time = start_time;
-    CONFIG.save(deps.storage, &config)?;
     Ok(Response::new()
-        .add_attribute("action", "update_start_time")
+        .add_attribute("action", "queue_airdrop")
+        .add_attribute("queued", recipients.len().to_string())
+        .add_attribute("remaining", remaining.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+// Mints the oldest queued airdrops on behalf of the admin, paid from the escrowed airdrop price.
+// Entries past the supply or the airdrop cap are dropped and their escrow refunded to the admin
+pub fn execute_process_airdrop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_batch_len(limit as usize)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let entries = AIRDROP_QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    let Some((_, (_, price))) = entries.first().cloned() else {
+        return Err(ContractError::AirdropQueueEmpty {});
+    };
+    // A single mint takes a single price, entries queued at another price wait for the next call
+    let entries = entries
+        .into_iter()
+        .take_while(|(_, (_, paid))| *paid == price)
+        .collect::<Vec<_>>();
+
+    let mut available = entries.len() as u32;
+    if let Some(mintable_num_tokens) = MINTABLE_NUM_TOKENS.may_load(deps.storage)? {
+        available = available.min(mintable_num_tokens);
+    }
+    if let Some(max_airdrop_tokens) = config.extension.max_airdrop_tokens {
+        let airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or_default();
+        available = available.min(max_airdrop_tokens.saturating_sub(airdrop_count));
+    }
+
+    for (seq, _) in entries.iter() {
+        AIRDROP_QUEUE.remove(deps.storage, *seq);
+    }
+    let remaining = AIRDROP_QUEUE_REMAINING.load(deps.storage)? - entries.len() as u32;
+    AIRDROP_QUEUE_REMAINING.save(deps.storage, &remaining)?;
+
+    let mut recipients = entries
+        .into_iter()
+        .map(|(_, (recipient, _))| recipient)
+        .collect::<Vec<_>>();
+    let dropped = recipients.split_off(available as usize);
+
+    let mut res = if recipients.is_empty() {
+        Response::new().add_attribute("action", "process_airdrop")
+    } else {
+        let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+        let total_price = price.amount.checked_mul(Uint128::from(available))?;
+        let escrow = MessageInfo {
+            sender: config.extension.admin.clone(),
+            funds: if total_price.is_zero() {
+                vec![]
+            } else {
+                vec![coin(total_price.u128(), &price.denom)]
+            },
+        };
+        _execute_mint(
+            deps,
+            env,
+            escrow,
+            "process_airdrop",
+            true,
+            price.clone(),
+            recipients,
+            None,
+            factory_params,
+        )?
+    };
+
+    let refund = price
+        .amount
+        .checked_mul(Uint128::from(dropped.len() as u128))?;
+    if !refund.is_zero() {
+        res = res.add_message(BankMsg::Send {
+            to_address: config.extension.admin.to_string(),
+            amount: vec![coin(refund.u128(), &price.denom)],
+        });
+    }
+
+    Ok(res
+        .add_attribute("processor", info.sender)
+        .add_attribute("dropped", dropped.len().to_string())
+        .add_attribute("remaining", remaining.to_string()))
+}
+
+// Unblocks the queue when its oldest entries fail to mint, e.g. past the airdrop fee cap
+pub fn execute_dequeue_airdrop(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_batch_len(limit as usize)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+
+    let (dequeued, refund) = dequeue_airdrops(deps.storage, limit as usize)?;
+    if dequeued == 0 {
+        return Err(ContractError::AirdropQueueEmpty {});
+    }
+    let remaining = AIRDROP_QUEUE_REMAINING.load(deps.storage)?;
+
+    let mut res = Response::new();
+    if !refund.is_empty() {
+        res = res.add_message(BankMsg::Send {
+            to_address: config.extension.admin.to_string(),
+            amount: refund,
+        });
+    }
+    Ok(res
+        .add_attribute("action", "dequeue_airdrop")
+        .add_attribute("dequeued", dequeued.to_string())
+        .add_attribute("remaining", remaining.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+// Removes up to `limit` of the oldest queued airdrops, returns how many went and their escrow
+fn dequeue_airdrops(
+    storage: &mut dyn Storage,
+    limit: usize,
+) -> Result<(u32, Vec<Coin>), ContractError> {
+    let entries = AIRDROP_QUEUE
+        .range(storage, None, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut escrow: Vec<Coin> = vec![];
+    for (seq, (_, paid)) in entries.iter() {
+        AIRDROP_QUEUE.remove(storage, *seq);
+        if paid.amount.is_zero() {
+            continue;
+        }
+        match escrow
+            .iter_mut()
+            .find(|escrowed| escrowed.denom == paid.denom)
+        {
+            Some(escrowed) => escrowed.amount = escrowed.amount.checked_add(paid.amount)?,
+            None => escrow.push(paid.clone()),
+        }
+    }
+    let remaining = AIRDROP_QUEUE_REMAINING
+        .may_load(storage)?
+        .unwrap_or_default()
+        - entries.len() as u32;
+    AIRDROP_QUEUE_REMAINING.save(storage, &remaining)?;
+    Ok((entries.len() as u32, escrow))
+}
+
+// A guardrail on the admin's own spending, so it stays settable once the config is frozen
+pub fn execute_update_max_airdrop_fee_spend(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_airdrop_fee_spend: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+
+    match max_airdrop_fee_spend {
+        Some(max_airdrop_fee_spend) => {
+            MAX_AIRDROP_FEE_SPEND.save(deps.storage, &max_airdrop_fee_spend)?
+        }
+        None => MAX_AIRDROP_FEE_SPEND.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_max_airdrop_fee_spend")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "max_airdrop_fee_spend",
+            max_airdrop_fee_spend.map_or("unlimited".to_string(), |max| max.to_string()),
+        ))
+}
+
+pub fn execute_set_allowlist(
+    deps: DepsMut,
+    info: MessageInfo,
+    entries: Vec<(String, u32)>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    ensure_batch_len(entries.len())?;
+
+    for (address, allocation) in entries.iter() {
+        let addr = deps.api.addr_validate(address)?;
+        if *allocation == 0 {
+            ALLOWLIST.remove(deps.storage, &addr);
+        } else {
+            ALLOWLIST.save(deps.storage, &addr, allocation)?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_allowlist")
+        .add_attribute("entries", entries.len().to_string())
+        .add_attribute("sender", info.sender))
+}
+
+// Replaces the addresses exempt from the per address limit, an empty list clears it
+pub fn execute_set_unlimited_addresses(
+    deps: DepsMut,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+    ensure_batch_len(addresses.len())?;
+
+    let unlimited_addresses = addresses
+        .iter()
+        .map(|address| deps.api.addr_validate(address))
+        .collect::<StdResult<Vec<_>>>()?;
+    config.extension.unlimited_addresses =
+        (!unlimited_addresses.is_empty()).then_some(unlimited_addresses);
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_unlimited_addresses")
+        .add_attribute("addresses", addresses.len().to_string())
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_mint_allowlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let action = "mint_allowlist";
+
+    // Allowlist minting is a presale, the public mint takes over at start time
+    if env.block.time >= config.extension.start_time {
+        return Err(ContractError::AllowlistMintEnded {});
+    }
+
+    let allocation = ALLOWLIST
+        .may_load(deps.storage, &info.sender)?
+        .ok_or_else(|| ContractError::NotWhitelisted {
+            addr: info.sender.to_string(),
+        })?;
+    if allocation == 0 {
+        return Err(ContractError::AllowlistExhausted {});
+    }
+    ALLOWLIST.save(deps.storage, &info.sender, &(allocation - 1))?;
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = config
+        .extension
+        .allowlist_mint_price
+        .unwrap_or(config.mint_price);
+    let recipients = vec![info.sender.clone()];
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        false,
+        mint_price,
+        recipients,
+        None,
+        factory_params,
+    )
+}
+
+pub fn execute_set_whitelist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    if env.block.time >= config.extension.start_time {
+        return Err(ContractError::AlreadyStarted {});
+    }
+
+    // A running presale can't be swapped out
+    if let Some(existing) = config.extension.whitelist.as_ref() {
+        if query_whitelist_config(deps.as_ref(), existing)?.is_active {
+            return Err(ContractError::WhitelistAlreadyStarted {});
+        }
+    }
+
+    let whitelist = deps.api.addr_validate(&address)?;
+    let wl_config = query_whitelist_config(deps.as_ref(), &whitelist)?;
+    if wl_config.is_active {
+        return Err(ContractError::WhitelistAlreadyStarted {});
+    }
+    if wl_config.mint_price.denom != config.mint_price.denom {
+        return Err(ContractError::InvalidDenom {
+            expected: config.mint_price.denom,
+            got: wl_config.mint_price.denom,
+        });
+    }
+
+    // Whitelist could be free, while factory minimum is not
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    if wl_config.mint_price.denom == factory_params.min_mint_price.denom
+        && wl_config.mint_price.amount < factory_params.min_mint_price.amount
+    {
+        return Err(ContractError::InsufficientWhitelistMintPrice {
+            expected: factory_params.min_mint_price.amount.u128(),
+            got: wl_config.mint_price.amount.u128(),
+        });
+    }
+
+    config.extension.whitelist = Some(whitelist.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_whitelist")
+        .add_attribute("whitelist", whitelist)
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_mint_whitelist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let action = "mint_whitelist";
+
+    // Whitelist minting is a presale, the public mint takes over at start time
+    if env.block.time >= config.extension.start_time {
+        return Err(ContractError::WhitelistMintEnded {});
+    }
+
+    let not_whitelisted = || ContractError::NotWhitelisted {
+        addr: info.sender.to_string(),
+    };
+    let whitelist = config
+        .extension
+        .whitelist
+        .as_ref()
+        .ok_or_else(not_whitelisted)?;
+    let wl_config = query_whitelist_config(deps.as_ref(), whitelist)?;
+    if !wl_config.is_active {
+        return Err(ContractError::WhitelistNotActive {});
+    }
+
+    let res: HasMemberResponse = deps
+        .querier
+        .query_wasm_smart(
+            whitelist,
+            &WhitelistQueryMsg::HasMember {
+                member: info.sender.to_string(),
+            },
+        )
+        .map_err(|_| ContractError::WhitelistQueryFailed {})?;
+    if !res.has_member {
+        return Err(not_whitelisted());
+    }
+
+    if mint_count_per_addr(deps.as_ref(), &info)? >= wl_config.per_address_limit {
+        return Err(ContractError::MaxPerAddressLimitExceeded {});
+    }
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let recipients = vec![info.sender.clone()];
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        false,
+        wl_config.mint_price,
+        recipients,
+        None,
+        factory_params,
+    )
+}
+
+pub fn execute_mint_as_holder(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let action = "mint_as_holder";
+
+    check_public_mint_window(&config, &env)?;
+
+    if HOLDER_CLAIMED.has(deps.storage, &info.sender) {
+        return Err(ContractError::HolderAlreadyClaimed {});
+    }
+
+    // Holding a single token of any allowed collection is enough, nothing is burned
+    let mut is_holder = false;
+    for collection in config.extension.allowed_holder_collections.iter().flatten() {
+        let res: TokensResponse = deps.querier.query_wasm_smart(
+            collection,
+            &Cw721QueryMsg::Tokens {
+                owner: info.sender.to_string(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )?;
+        if !res.tokens.is_empty() {
+            is_holder = true;
+            break;
+        }
+    }
+    if !is_holder {
+        return Err(ContractError::NotAHolder {});
+    }
+    HOLDER_CLAIMED.save(deps.storage, &info.sender, &true)?;
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_price = coin(0, &config.mint_price.denom);
+    let recipients = vec![info.sender.clone()];
+    _execute_mint(
+        deps,
+        env,
+        info,
+        action,
+        false,
+        mint_price,
+        recipients,
+        None,
+        factory_params,
+    )
+}
+
+// The price is checked against the token's info so a typo can't lock the mint
+fn validate_cw20_mint_price(
+    deps: Deps,
+    price: Cw20Coin,
+) -> Result<Cw20CoinVerified, ContractError> {
+    if price.amount.is_zero() {
+        return Err(ContractError::InvalidCw20MintPrice {});
+    }
+    let address = deps.api.addr_validate(&price.address)?;
+    deps.querier
+        .query_wasm_smart::<TokenInfoResponse>(&address, &Cw20QueryMsg::TokenInfo {})
+        .map_err(|_| ContractError::InvalidCw20MintPrice {})?;
+    Ok(Cw20CoinVerified {
+        address,
+        amount: price.amount,
+    })
+}
+
+// Entry point of the cw20 `Send` hook, the sender is the cw20 contract
+pub fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let Some(price) = config.extension.cw20_mint_price else {
+        return Err(ContractError::Cw20PaymentDisabled {});
+    };
+    if info.sender != price.address {
+        return Err(ContractError::InvalidCw20Token(info.sender.to_string()));
+    }
+    let sender = deps.api.addr_validate(&msg.sender)?;
+
+    match from_json(&msg.msg)? {
+        ReceiveMsg::Mint {} => execute_cw20_mint(deps, env, sender, msg.amount, price),
+    }
+}
+
+// Public mint paid in cw20 tokens, held to the same checks as a native public mint. Fair burn
+// only handles native funds, so the network fee is transferred to the dev fee address. The
+// seller amount goes out like a native one, the platform fee only applies to native mints
+fn execute_cw20_mint(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    amount: Uint128,
+    price: Cw20CoinVerified,
+) -> Result<Response, ContractError> {
+    if amount != price.amount {
+        return Err(ContractError::IncorrectCw20PaymentAmount {
+            got: amount,
+            expected: price.amount,
+        });
+    }
+    check_can_mint(deps.as_ref(), &env, &sender)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mintable_num_tokens = MINTABLE_NUM_TOKENS.may_load(deps.storage)?;
+    let mint_count = MINTER_ADDRS
+        .may_load(deps.storage, &sender)?
+        .unwrap_or_default();
+
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?.params;
+    let mint_fee = mint_fee_rate(deps.as_ref(), &factory_params, false)?;
+    let network_fee = apply_fee(price.amount, mint_fee)?;
+    let seller_amount = price.amount.checked_sub(network_fee)?;
+
+    let mut res = Response::new();
+    if !network_fee.is_zero() {
+        let dev_fee_address = match &config.extension.dev_fee_address_override {
+            Some(dev_fee_address) => dev_fee_address.clone(),
+            None => deps
+                .api
+                .addr_validate(&factory_params.extension.dev_fee_address)?,
+        };
+        res = res.add_message(cw20_transfer_msg(
+            &price.address,
+            &dev_fee_address,
+            network_fee,
+        )?);
+    }
+    for (recipient, amount) in seller_payouts(&config, seller_amount) {
+        res = res.add_message(cw20_transfer_msg(&price.address, &recipient, amount)?);
+    }
+
+    let token_index = increment_token_index(deps.storage)?;
+    let token_id = prefixed_token_id(config.extension.token_id_prefix.as_deref(), token_index);
+    let (extension, token_uri) =
+        seeded_token_metadata(deps.branch(), &env, &config, token_index, &token_id)?;
+    res = res.add_message(mint_nft_msg(
+        SG721_ADDRESS.load(deps.storage)?,
+        token_id.clone(),
+        sender.clone(),
+        extension,
+        token_uri,
+    )?);
+
+    MINTER_ADDRS.save(deps.storage, &sender, &(mint_count + 1))?;
+    record_rate_limited_mints(deps.storage, &env, &config, &sender, 1)?;
+    if mint_count == 0 {
+        record_unique_minter(deps.storage, env.block.height)?;
+    }
+    TOTAL_MINT_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    if let Some(mintable_nb_tokens) = mintable_num_tokens {
+        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_nb_tokens - 1))?;
+    }
+    // Revenue of cw20 mints is tracked under the token's address
+    record_revenue(
+        deps.storage,
+        price.address.as_str(),
+        seller_amount,
+        network_fee,
+    )?;
+
+    Ok(res
+        .add_attribute("action", "mint_cw20")
+        .add_attribute("sender", &sender)
+        .add_attribute("recipient", sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("cw20", price.address)
+        .add_attribute("network_fee", network_fee)
+        .add_attribute("mint_price", price.amount)
+        .add_attribute("seller_amount", seller_amount))
+}
+
+// Embeds the token's random seed as a trait of its on-chain metadata, off-chain metadata is left as is
+fn seeded_token_metadata(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    token_index: u64,
+    token_id: &str,
+) -> Result<(Option<Metadata>, Option<String>), ContractError> {
+    let (mut extension, token_uri) = config.extension.token_metadata(token_index);
+    if let Some(extension) = extension.as_mut() {
+        let seed = match &config.extension.randomness_provider {
+            Some(provider) => {
+                deps.querier
+                    .query_wasm_smart::<RandomSeedResponse>(
+                        provider,
+                        &RandomnessProviderQueryMsg::RandomSeed {
+                            token_id: token_id.to_string(),
+                        },
+                    )?
+                    .seed
+            }
+            None => fallback_token_seed(env.block.time, token_id),
+        };
+        extension
+            .attributes
+            .get_or_insert_with(Vec::new)
+            .push(Trait {
+                display_type: None,
+                trait_type: SEED_TRAIT_TYPE.to_string(),
+                value: seed.to_hex(),
+            });
+        TOKEN_SEEDS.save(deps.storage, token_id, &seed)?;
+    }
+    Ok((extension, token_uri))
+}
+
+fn cw20_transfer_msg(token: &Addr, recipient: &Addr, amount: Uint128) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    })
+}
+
+fn query_whitelist_config(
+    deps: Deps,
+    whitelist: &Addr,
+) -> Result<WhitelistConfigResponse, ContractError> {
+    deps.querier
+        .query_wasm_smart(whitelist, &WhitelistQueryMsg::Config {})
+        .map_err(|_| ContractError::WhitelistQueryFailed {})
+}
+
+// Generalize checks and mint message creation, one token is minted per recipient
+// mint -> _execute_mint(recipients: [sender])
+// mint_for(recipient: "cold wallet") -> _execute_mint(recipients: [cold wallet])
+// mint_to(recipient: "friend") -> _execute_mint(recipients: [friend])
+// mint_to_batch(recipients) -> _execute_mint(recipients), all or nothing
+// Factory params are fetched once by the caller and reused for the price and the fees
+#[allow(clippy::too_many_arguments)]
+fn _execute_mint(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: &str,
+    is_admin: bool,
+    mint_price: Coin,
+    recipients: Vec<Addr>,
+    referrer: Option<Addr>,
+    factory_params: OpenEditionMinterParams,
+) -> Result<Response, ContractError> {
+    let mint_num = recipients.len() as u32;
+    let config = CONFIG.load(deps.storage)?;
+    let mintable_num_tokens =
+        check_mint_gates(deps.storage, &config, &info.sender, mint_num, is_admin)?;
+
+    // Public mints of a cw20 priced mint go through the cw20 `Receive` hook
+    if !is_admin && config.extension.cw20_mint_price.is_some() {
+        return Err(ContractError::Cw20PaymentRequired {});
+    }
+
+    // Admin mints are capped separately so airdrops can't dilute the edition
+    if is_admin {
+        let airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or_default();
+        if let Some(max_airdrop_tokens) = config.extension.max_airdrop_tokens {
+            if airdrop_count + mint_num > max_airdrop_tokens {
+                return Err(ContractError::AirdropLimitReached {});
+            }
+        }
+        AIRDROP_COUNT.save(deps.storage, &(airdrop_count + mint_num))?;
+    }
+
+    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+
+    let total_price = mint_price.amount.checked_mul(Uint128::from(mint_num))?;
+    // Exact payment only accepted
+    let payment = may_pay(&info, &mint_price.denom)?;
+    if payment != total_price {
+        return Err(ContractError::IncorrectPaymentAmount(
+            coin(payment.u128(), &config.mint_price.denom),
+            coin(total_price.u128(), &mint_price.denom),
+        ));
+    }
+
+    let mut res = Response::new();
+
+    // Create fee msgs
+    // Metadata Storage fees -> minting fee will be enabled for on-chain metadata mints
+    // dev fees are intrinsic in the mint fee (assuming a 50% share)
+    let mint_fee = mint_fee_rate(deps.as_ref(), &factory_params, is_admin)?;
+    // The fee is applied once per minted token
+    let network_fee =
+        apply_fee(mint_price.amount, mint_fee)?.checked_mul(Uint128::from(mint_num))?;
+
+    // The whole batch is rejected if its fees go past the admin's airdrop fee cap
+    if is_admin {
+        let airdrop_fee_spent = AIRDROP_FEE_SPENT
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .checked_add(network_fee)?;
+        if let Some(max_airdrop_fee_spend) = MAX_AIRDROP_FEE_SPEND.may_load(deps.storage)? {
+            if airdrop_fee_spent > max_airdrop_fee_spend {
+                return Err(ContractError::AirdropFeeCapReached {});
+            }
+        }
+        AIRDROP_FEE_SPENT.save(deps.storage, &airdrop_fee_spent)?;
+    }
+
+    // The minter's own dev fee address takes precedence over the factory default
+    let dev_fee_address = match &config.extension.dev_fee_address_override {
+        Some(dev_fee_address) => dev_fee_address.clone(),
+        None => deps
+            .api
+            .addr_validate(&factory_params.extension.dev_fee_address)?,
+    };
+
+    // This is for the network fee msg
+    // send non-native fees to community pool
+    if mint_price.denom != NATIVE_DENOM {
+        // only send non-zero amounts
+        // send portion to dev addr
+        if !network_fee.is_zero() {
+            ibc_denom_fair_burn(
+                coin(network_fee.u128(), mint_price.denom.to_string()),
+                Some(dev_fee_address),
+                &mut res,
+            )?;
+        }
+    } else if !network_fee.is_zero() {
+        checked_fair_burn(&info, network_fee.u128(), Some(dev_fee_address), &mut res)?;
+    }
+
+    // The platform fee is deducted from the seller's proceeds, applied once per minted token.
+    // Free mints pay nothing and admin airdrops are exempt
+    let platform_fee = match (
+        factory_params.extension.platform_fee_bps,
+        &factory_params.extension.platform_fee_address,
+    ) {
+        (Some(platform_fee_bps), Some(platform_fee_address)) if !is_admin => {
+            let amount = apply_fee(mint_price.amount, Decimal::bps(platform_fee_bps))?
+                .checked_mul(Uint128::from(mint_num))?;
+            if !amount.is_zero() {
+                res = res.add_message(BankMsg::Send {
+                    to_address: deps.api.addr_validate(platform_fee_address)?.to_string(),
+                    amount: vec![coin(amount.u128(), &mint_price.denom)],
+                });
+            }
+            Some(amount)
+        }
+        _ => None,
+    };
+
+    let mut minted_attributes = vec![];
+    for recipient_addr in recipients {
+        // Token ID to mint + update the config counter
+        let token_index = increment_token_index(deps.storage)?;
+        let token_id = prefixed_token_id(config.extension.token_id_prefix.as_deref(), token_index);
+
+        // Create mint msg -> dependents on the NFT data type
+        let (extension, token_uri) =
+            seeded_token_metadata(deps.branch(), &env, &config, token_index, &token_id)?;
+        let msg = mint_nft_msg(
+            sg721_address.clone(),
+            token_id.clone(),
+            recipient_addr.clone(),
+            extension,
+            token_uri,
+        )?;
+        res = res.add_message(msg);
+        minted_attributes.push(("recipient", recipient_addr.to_string()));
+        minted_attributes.push(("token_id", token_id));
+    }
+
+    // Save the new mint count for the sender's address
+    let mint_count = mint_count_per_addr(deps.as_ref(), &info)?;
+    MINTER_ADDRS.save(deps.storage, &info.sender, &(mint_count + mint_num))?;
+
+    // First mint for this address -> checkpoint the unique minters curve
+    if mint_count == 0 {
+        record_unique_minter(deps.storage, env.block.height)?;
+    }
+
+    // Update the mint count
+    TOTAL_MINT_COUNT.update(
+        deps.storage,
+        |mut updated_mint_count| -> Result<_, ContractError> {
+            updated_mint_count += mint_num;
+            Ok(updated_mint_count)
+        },
+    )?;
+
+    // Update mintable count (optional)
+    if let Some(mintable_nb_tokens) = mintable_num_tokens {
+        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_nb_tokens - mint_num))?;
+    }
+
+    // The referral cut is deducted from the seller's proceeds
+    let referral_amount = match &referrer {
+        Some(referrer) => {
+            let amount =
+                total_price.checked_sub(network_fee)? * Decimal::bps(config.extension.referral_bps);
+            if !amount.is_zero() {
+                res = res.add_message(BankMsg::Send {
+                    to_address: referrer.to_string(),
+                    amount: vec![coin(amount.u128(), &mint_price.denom)],
+                });
+            }
+            amount
+        }
+        None => Uint128::zero(),
+    };
+
+    // the net amount is mint price - network fee (mint free + dev fee) - referral cut - platform fee
+    let seller_amount = total_price
+        .checked_sub(network_fee)?
+        .checked_sub(referral_amount)?
+        .checked_sub(platform_fee.unwrap_or_default())?;
+    for (recipient, amount) in seller_payouts(&config, seller_amount) {
+        res = res.add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(amount.u128(), &mint_price.denom)],
+        });
+    }
+    record_revenue(deps.storage, &mint_price.denom, seller_amount, network_fee)?;
+
+    if let Some(platform_fee) = platform_fee {
+        res = res.add_attribute(
+            "platform_fee",
+            coin(platform_fee.into(), &mint_price.denom).to_string(),
+        );
+    }
+
+    if let Some(referrer) = referrer {
+        res = res.add_attribute("referrer", referrer).add_attribute(
+            "referral_amount",
+            coin(referral_amount.into(), &mint_price.denom).to_string(),
+        );
+    }
+
+    Ok(res
+        .add_attribute("action", action)
+        .add_attribute("sender", info.sender)
+        .add_attributes(minted_attributes)
+        .add_attribute(
+            "network_fee",
+            coin(network_fee.into(), mint_price.clone().denom).to_string(),
+        )
+        .add_attribute("mint_price", mint_price.to_string())
+        .add_attribute(
+            "seller_amount",
+            coin(seller_amount.into(), mint_price.denom).to_string(),
+        ))
+}
+
+// Freezing is irreversible, mints keep working but the admin can't update the mint anymore
+pub fn execute_freeze_config(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+
+    CONFIG_FROZEN.save(deps.storage, &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "freeze_config")
+        .add_attribute("sender", info.sender))
+}
+
+// Cancelling is terminal, it also freezes the configuration so the mint can't be revived
+pub fn execute_cancel_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    if MINT_CANCELLED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::MintCancelled {});
+    }
+    if env.block.time >= config.extension.start_time {
+        return Err(ContractError::AlreadyStarted {});
+    }
+
+    MINT_CANCELLED.save(deps.storage, &true)?;
+    CONFIG_FROZEN.save(deps.storage, &true)?;
+
+    // The queue can't be processed anymore, only its escrow is refunded
+    let (_, escrow) = dequeue_airdrops(deps.storage, usize::MAX)?;
+    let mut res = Response::new();
+    if !escrow.is_empty() {
+        res = res.add_message(BankMsg::Send {
+            to_address: config.extension.admin.to_string(),
+            amount: escrow,
+        });
+    }
+
+    Ok(res
+        .add_attribute("action", "cancel_mint")
+        .add_attribute("sender", info.sender))
+}
+
+fn must_not_be_frozen(storage: &dyn Storage) -> Result<(), ContractError> {
+    if CONFIG_FROZEN.may_load(storage)?.unwrap_or_default() {
+        return Err(ContractError::ConfigFrozen {});
+    }
+    Ok(())
+}
+
+pub fn execute_update_mint_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    price: u128,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+
+    if let Some(end_time) = config.extension.end_time {
+        if env.block.time >= end_time {
+            return Err(ContractError::AfterMintEndTime {});
+        }
+    }
+
+    // If current time is after the stored start_time, only allow lowering price
+    if env.block.time >= config.extension.start_time && price >= config.mint_price.amount.u128() {
+        return Err(ContractError::UpdatedMintPriceTooHigh {
+            allowed: config.mint_price.amount.u128(),
+            updated: price,
+        });
+    }
+
+    let factory = query_factory_params(deps.as_ref(), &config.factory)?;
+    let factory_params = factory.params;
+
+    if !config.extension.free_mint
+        && config.mint_price.denom == factory_params.min_mint_price.denom
+        && factory_params.min_mint_price.amount.u128() > price
+    {
+        return Err(ContractError::InsufficientMintPrice {
+            expected: factory_params.min_mint_price.amount.u128(),
+            got: price,
+        });
+    }
+
+    config.mint_price = coin(price, config.mint_price.denom);
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_mint_price")
+        .add_attribute("sender", info.sender)
+        .add_attribute("mint_price", config.mint_price.to_string()))
+}
+
+pub fn execute_update_start_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Timestamp,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+    // If current time is after the stored start time return error
+    if env.block.time >= config.extension.start_time {
+        return Err(ContractError::AlreadyStarted {});
+    }
+
+    // If current time already passed the new start_time return error
+    if env.block.time > start_time {
+        return Err(ContractError::InvalidStartTime(start_time, env.block.time));
+    }
+
+    // If the new start_time is after end_time return error
+    if let Some(end_time) = config.extension.end_time {
+        if start_time > end_time {
+            return Err(ContractError::InvalidStartTime(end_time, start_time));
+        }
+    }
+
+    config.extension.start_time = start_time;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_start_time")
         .add_attribute("sender", info.sender)
         .add_attribute("start_time", start_time.to_string()))
 }
@@ -524,6 +1979,7 @@ pub fn execute_update_end_time(
             "Sender is not an admin".to_owned(),
         ));
     }
+    must_not_be_frozen(deps.storage)?;
     // If current time is after the stored end time return error
     if let Some(end_time_u) = config.extension.end_time {
         if env.block.time >= end_time_u {
@@ -555,6 +2011,44 @@ pub fn execute_update_end_time(
         .add_attribute("end_time", end_time.to_string()))
 }
 
+// Unlike UpdateEndTime, only possible once the mint has ended
+pub fn execute_reopen_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_end_time: Timestamp,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+    if MINT_FORCE_ENDED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::MintForceEnded {});
+    }
+    let end_time = config
+        .extension
+        .end_time
+        .ok_or(ContractError::NoEndTimeInitiallyDefined {})?;
+    if env.block.time < end_time {
+        return Err(ContractError::MintNotEnded {});
+    }
+    if new_end_time <= env.block.time {
+        return Err(ContractError::InvalidEndTime(new_end_time, env.block.time));
+    }
+
+    config.extension.end_time = Some(new_end_time);
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "reopen_mint")
+        .add_attribute("sender", info.sender)
+        .add_attribute("reopened", end_time.to_string())
+        .add_attribute("end_time", new_end_time.to_string()))
+}
+
 pub fn execute_update_start_trading_time(
     deps: DepsMut,
     env: Env,
@@ -570,11 +2064,20 @@ pub fn execute_update_start_trading_time(
             "Sender is not an admin".to_owned(),
         ));
     }
+    must_not_be_frozen(deps.storage)?;
 
-    // add custom rules here
-    let factory_params: ParamsResponse = deps
+    // Once trading is open the collection's start trading time is frozen
+    let collection_info: CollectionInfoResponse = deps
         .querier
-        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+        .query_wasm_smart(&sg721_contract_addr, &Sg721QueryMsg::CollectionInfo {})?;
+    if let Some(current) = collection_info.start_trading_time {
+        if current <= env.block.time {
+            return Err(ContractError::TradingAlreadyStarted(current));
+        }
+    }
+
+    // add custom rules here
+    let factory_params = query_factory_params(deps.as_ref(), &config.factory)?;
     let default_start_time_with_offset = config
         .extension
         .start_time
@@ -596,17 +2099,67 @@ pub fn execute_update_start_trading_time(
         }
     }
 
-    // execute sg721 contract
+    // execute sg721 contract
+    let msg = WasmMsg::Execute {
+        contract_addr: sg721_contract_addr.to_string(),
+        msg: to_json_binary(&Sg721ExecuteMsg::<Empty, Empty>::UpdateStartTradingTime(
+            start_time,
+        ))?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "update_start_trading_time")
+        .add_attribute("sender", info.sender)
+        .add_message(msg))
+}
+
+pub fn execute_update_collection_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    description: Option<String>,
+    image: Option<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let sg721_contract_addr = SG721_ADDRESS.load(deps.storage)?;
+
+    if info.sender != config.extension.admin {
+        return Err(ContractError::Unauthorized(
+            "Sender is not an admin".to_owned(),
+        ));
+    }
+    must_not_be_frozen(deps.storage)?;
+
+    // Same checks as sg721, so a bad value fails here with a clear error
+    if let Some(description) = &description {
+        validate_collection_description(description)?;
+    }
+    if let Some(image) = &image {
+        Url::parse(image)?;
+    }
+
+    // sg721 overwrites the explicit content flag, pass the current one along
+    let collection_info: CollectionInfoResponse = deps
+        .querier
+        .query_wasm_smart(&sg721_contract_addr, &Sg721QueryMsg::CollectionInfo {})?;
     let msg = WasmMsg::Execute {
         contract_addr: sg721_contract_addr.to_string(),
-        msg: to_json_binary(&Sg721ExecuteMsg::<Empty, Empty>::UpdateStartTradingTime(
-            start_time,
-        ))?,
+        msg: to_json_binary(&Sg721ExecuteMsg::<Empty, Empty>::UpdateCollectionInfo {
+            collection_info: UpdateCollectionInfoMsg {
+                description,
+                image,
+                external_link: None,
+                explicit_content: collection_info.explicit_content,
+                royalty_info: None,
+                creator: None,
+            },
+        })?,
         funds: vec![],
     };
 
     Ok(Response::new()
-        .add_attribute("action", "update_start_trading_time")
+        .add_attribute("action", "update_collection_info")
         .add_attribute("sender", info.sender)
         .add_message(msg))
 }
@@ -624,10 +2177,9 @@ pub fn execute_update_per_address_limit(
             "Sender is not an admin".to_owned(),
         ));
     }
+    must_not_be_frozen(deps.storage)?;
 
-    let factory: ParamsResponse = deps
-        .querier
-        .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+    let factory = query_factory_params(deps.as_ref(), &config.factory)?;
     let factory_params = factory.params;
 
     if per_address_limit == 0 || per_address_limit > factory_params.extension.max_per_address_limit
@@ -641,6 +2193,8 @@ pub fn execute_update_per_address_limit(
 
     config.extension.per_address_limit = per_address_limit;
     CONFIG.save(deps.storage, &config)?;
+    // Back within the factory bounds
+    PER_ADDRESS_LIMIT_OVERRIDDEN.remove(deps.storage);
     Ok(Response::new()
         .add_attribute("action", "update_per_address_limit")
         .add_attribute("sender", info.sender)
@@ -656,17 +2210,29 @@ pub fn mint_price(deps: Deps, is_admin: bool) -> Result<Coin, StdError> {
     if is_admin {
         let factory: ParamsResponse = deps
             .querier
-            .query_wasm_smart(config.factory, &Sg2QueryMsg::Params {})?;
-        let factory_params = factory.params;
-        Ok(coin(
-            factory_params.extension.airdrop_mint_price.amount.u128(),
-            factory_params.extension.airdrop_mint_price.denom,
-        ))
+            .query_wasm_smart(config.factory.clone(), &Sg2QueryMsg::Params {})?;
+        Ok(mint_price_with_params(&config, &factory.params, is_admin))
     } else {
         Ok(config.mint_price)
     }
 }
 
+// Same as mint_price, for callers that already fetched the factory params
+fn mint_price_with_params(
+    config: &Config,
+    factory_params: &OpenEditionMinterParams,
+    is_admin: bool,
+) -> Coin {
+    if is_admin {
+        coin(
+            factory_params.extension.airdrop_mint_price.amount.u128(),
+            &factory_params.extension.airdrop_mint_price.denom,
+        )
+    } else {
+        config.mint_price.clone()
+    }
+}
+
 pub fn execute_burn_remaining(
     deps: DepsMut,
     env: Env,
@@ -708,13 +2274,85 @@ pub fn execute_burn_remaining(
     Ok(Response::new().add_event(event))
 }
 
+// Admin mints pay the airdrop fee, verified minters may pay a reduced fee
+fn mint_fee_rate(
+    deps: Deps,
+    factory_params: &OpenEditionMinterParams,
+    is_admin: bool,
+) -> StdResult<Decimal> {
+    if is_admin {
+        return Ok(Decimal::bps(factory_params.extension.airdrop_mint_fee_bps));
+    }
+    if STATUS.load(deps.storage)?.is_verified {
+        if let Some(verified_mint_fee_bps) = factory_params.extension.verified_mint_fee_bps {
+            return Ok(Decimal::bps(verified_mint_fee_bps));
+        }
+    }
+    Ok(Decimal::bps(factory_params.mint_fee_bps))
+}
+
+// Shares are rounded down, the remaining dust goes to the first recipient
+// Recipients of the seller amount, whatever it is paid in. The proceeds contract takes
+// precedence over the payment splits, then the payment address and the admin.
+// Zero amounts are left out since sending 0 coins fails
+fn seller_payouts(config: &Config, amount: Uint128) -> Vec<(Addr, Uint128)> {
+    let payouts = match (
+        &config.extension.proceeds_contract,
+        &config.extension.payment_splits,
+    ) {
+        (Some(proceeds_contract), _) => vec![(proceeds_contract.clone(), amount)],
+        (None, Some(payment_splits)) => payment_split_shares(amount, payment_splits),
+        (None, None) => vec![(
+            config
+                .extension
+                .payment_address
+                .clone()
+                .unwrap_or_else(|| config.extension.admin.clone()),
+            amount,
+        )],
+    };
+    payouts
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect()
+}
+
+fn payment_split_shares(
+    amount: Uint128,
+    payment_splits: &[(Addr, Decimal)],
+) -> Vec<(Addr, Uint128)> {
+    let mut shares: Vec<Uint128> = payment_splits
+        .iter()
+        .map(|(_, share)| amount * *share)
+        .collect();
+    let distributed = shares
+        .iter()
+        .fold(Uint128::zero(), |total, share| total + share);
+    shares[0] += amount - distributed;
+
+    payment_splits
+        .iter()
+        .map(|(recipient, _)| recipient.clone())
+        .zip(shares)
+        .collect()
+}
+
+// Factory params are required on most paths, surface a clear error if the factory can't answer
+fn query_factory_params(deps: Deps, factory: &Addr) -> Result<ParamsResponse, ContractError> {
+    deps.querier
+        .query_wasm_smart(factory, &Sg2QueryMsg::Params {})
+        .map_err(|_| ContractError::FactoryQueryFailed {
+            factory: factory.to_string(),
+        })
+}
+
 fn mint_count_per_addr(deps: Deps, info: &MessageInfo) -> Result<u32, StdError> {
     let mint_count = (MINTER_ADDRS.key(&info.sender).may_load(deps.storage)?).unwrap_or(0);
     Ok(mint_count)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
     match msg {
         SudoMsg::UpdateStatus {
             is_verified,
@@ -722,7 +2360,103 @@ pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, Contract
             is_explicit,
         } => update_status(deps, is_verified, is_blocked, is_explicit)
             .map_err(|_| ContractError::UpdateStatus {}),
+        SudoMsg::ForceEndMint {} => sudo_force_end_mint(deps, env),
+        SudoMsg::SetSg721Address { address } => sudo_set_sg721_address(deps, address),
+        SudoMsg::OverridePerAddressLimit { limit } => sudo_override_per_address_limit(deps, limit),
+        SudoMsg::MigrateCollection { new_code_id } => {
+            sudo_migrate_collection(deps, env, new_code_id)
+        }
+    }
+}
+
+/// Lets governance stop a mint without blocking the whole contract
+pub fn sudo_force_end_mint(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Never push back an end time that is already in the past
+    let end_time = match config.extension.end_time {
+        Some(end_time) if end_time < env.block.time => end_time,
+        _ => env.block.time,
+    };
+    config.extension.end_time = Some(end_time);
+    CONFIG.save(deps.storage, &config)?;
+    MINT_FORCE_ENDED.save(deps.storage, &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_force_end_mint")
+        .add_attribute("end_time", end_time.to_string()))
+}
+
+/// Lets governance wire the collection of a minter whose sg721 reply never saved it
+pub fn sudo_set_sg721_address(deps: DepsMut, address: String) -> Result<Response, ContractError> {
+    if SG721_ADDRESS.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::Sg721AddressAlreadySet {});
+    }
+    let sg721_address = deps.api.addr_validate(&address)?;
+    SG721_ADDRESS.save(deps.storage, &sg721_address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_set_sg721_address")
+        .add_attribute("sg721_address", sg721_address))
+}
+
+/// Lets governance grant a single minter a per address limit above the factory max
+pub fn sudo_override_per_address_limit(
+    deps: DepsMut,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    if limit == 0 {
+        return Err(ContractError::InvalidPerAddressLimit {
+            max: u32::MAX,
+            min: 1,
+            got: limit,
+        });
+    }
+    let mut config = CONFIG.load(deps.storage)?;
+    config.extension.per_address_limit = limit;
+    CONFIG.save(deps.storage, &config)?;
+    PER_ADDRESS_LIMIT_OVERRIDDEN.save(deps.storage, &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_override_per_address_limit")
+        .add_attribute("limit", limit.to_string())
+        .add_attribute("limit_overridden", "true"))
+}
+
+/// Lets governance upgrade the collection to a patched sg721 code id
+pub fn sudo_migrate_collection(
+    deps: DepsMut,
+    env: Env,
+    new_code_id: u64,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+
+    let res: AllowedCollectionCodeIdResponse = deps.querier.query_wasm_smart(
+        &config.factory,
+        &Sg2QueryMsg::AllowedCollectionCodeId(new_code_id),
+    )?;
+    if !res.allowed {
+        return Err(ContractError::CollectionCodeIdNotAllowed(new_code_id));
     }
+    // Collections instantiated before the minter became their admin keep the creator as admin
+    let collection_info = deps.querier.query_wasm_contract_info(&sg721_address)?;
+    if collection_info.admin != Some(env.contract.address.to_string()) {
+        return Err(ContractError::NotCollectionAdmin {});
+    }
+
+    config.collection_code_id = new_code_id;
+    CONFIG.save(deps.storage, &config)?;
+
+    let msg = WasmMsg::Migrate {
+        contract_addr: sg721_address.to_string(),
+        new_code_id,
+        msg: to_json_binary(&Empty {})?,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_migrate_collection")
+        .add_attribute("new_code_id", new_code_id.to_string())
+        .add_message(msg))
 }
 
 /// Only governance can update contract params
@@ -733,17 +2467,28 @@ pub fn update_status(
     is_explicit: bool,
 ) -> StdResult<Response> {
     let mut status = STATUS.load(deps.storage)?;
+    let was_blocked = status.is_blocked;
     status.is_verified = is_verified;
     status.is_blocked = is_blocked;
     status.is_explicit = is_explicit;
+    STATUS.save(deps.storage, &status)?;
 
-    Ok(Response::new().add_attribute("action", "sudo_update_status"))
+    let event = Event::new("status-update")
+        .add_attribute("is_verified", is_verified.to_string())
+        .add_attribute("is_blocked", is_blocked.to_string())
+        .add_attribute("is_explicit", is_explicit.to_string())
+        .add_attribute("was_blocked", was_blocked.to_string());
+
+    Ok(Response::new()
+        .add_attribute("action", "sudo_update_status")
+        .add_event(event))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::ResolvedConfig {} => to_json_binary(&query_resolved_config(deps)?),
         QueryMsg::Status {} => to_json_binary(&query_status(deps)?),
         QueryMsg::StartTime {} => to_json_binary(&query_start_time(deps)?),
         QueryMsg::EndTime {} => to_json_binary(&query_end_time(deps)?),
@@ -751,14 +2496,44 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::MintCount { address } => {
             to_json_binary(&query_mint_count_per_address(deps, address)?)
         }
+        QueryMsg::MintCounts { addresses } => to_json_binary(&query_mint_counts(deps, addresses)?),
         QueryMsg::TotalMintCount {} => to_json_binary(&query_mint_count(deps)?),
         QueryMsg::MintableNumTokens {} => to_json_binary(&query_mintable_num_tokens(deps)?),
+        QueryMsg::UniqueMinterCurve { start_after, limit } => {
+            to_json_binary(&query_unique_minter_curve(deps, start_after, limit)?)
+        }
+        QueryMsg::Revenue {} => to_json_binary(&query_revenue(deps)?),
+        QueryMsg::FullStatus {} => to_json_binary(&query_full_status(deps)?),
+        QueryMsg::AllowlistAllocation { address } => {
+            to_json_binary(&query_allowlist_allocation(deps, address)?)
+        }
+        QueryMsg::Collection {} => to_json_binary(&query_collection(deps)?),
+        QueryMsg::FactoryParams {} => to_json_binary(&query_live_factory_params(deps)?),
+        QueryMsg::QuoteBatch { count, address } => {
+            to_json_binary(&query_quote_batch(deps, env, count, address)?)
+        }
+        QueryMsg::TimeRemaining {} => to_json_binary(&query_time_remaining(deps, env)?),
+        QueryMsg::ShuffleSeed {} => to_json_binary(&query_shuffle_seed(deps, env)?),
+        QueryMsg::SimulateMint { sender, is_admin } => {
+            to_json_binary(&query_simulate_mint(deps, sender, is_admin)?)
+        }
+        QueryMsg::FeeSchedule {} => to_json_binary(&query_fee_schedule(deps)?),
+        QueryMsg::CurrentTokenIndex {} => to_json_binary(&query_current_token_index(deps)?),
+        QueryMsg::UnlimitedAddresses {} => to_json_binary(&query_unlimited_addresses(deps)?),
+        QueryMsg::Summary {} => to_json_binary(&query_summary(deps)?),
+        QueryMsg::NextTokenMetadata {} => to_json_binary(&query_next_token_metadata(deps)?),
+        QueryMsg::TokenSeed { token_id } => to_json_binary(&query_token_seed(deps, token_id)?),
+        QueryMsg::AirdropQueue {} => to_json_binary(&query_airdrop_queue(deps)?),
+        QueryMsg::AirdropFee { count } => to_json_binary(&query_airdrop_fee(deps, count)?),
+        QueryMsg::CanMint { address } => to_json_binary(&query_can_mint(deps, env, address)?),
+        QueryMsg::ContractInfo {} => to_json_binary(&query_contract_info(deps)?),
     }
 }
 
 fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let config = CONFIG.load(deps.storage)?;
     let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+    let airdrop_count = AIRDROP_COUNT.may_load(deps.storage)?.unwrap_or_default();
 
     Ok(ConfigResponse {
         admin: config.extension.admin.to_string(),
@@ -772,6 +2547,89 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         start_time: config.extension.start_time,
         mint_price: config.mint_price,
         factory: config.factory.to_string(),
+        lifetime_per_address_cap: config.extension.lifetime_per_address_cap,
+        payment_splits: config.extension.payment_splits,
+        allowlist_mint_price: config.extension.allowlist_mint_price,
+        max_airdrop_tokens: config.extension.max_airdrop_tokens,
+        airdrop_count,
+        referral_bps: config.extension.referral_bps,
+        purge_delay_secs: config.extension.purge_delay_secs,
+        start_height: config.extension.start_height,
+        extension_pool: config.extension.extension_pool,
+        whitelist: config.extension.whitelist.map(|w| w.to_string()),
+        limit_overridden: PER_ADDRESS_LIMIT_OVERRIDDEN
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+        allowed_holder_collections: config.extension.allowed_holder_collections,
+        token_id_prefix: config.extension.token_id_prefix,
+        proceeds_contract: config.extension.proceeds_contract,
+        dev_fee_address_override: config.extension.dev_fee_address_override,
+        per_tx_limit: config.extension.per_tx_limit,
+        cw20_mint_price: config.extension.cw20_mint_price,
+        randomness_provider: config.extension.randomness_provider,
+        allow_airdrop_after_end: config.extension.allow_airdrop_after_end,
+        free_mint: config.extension.free_mint,
+        reserved_tokens: config.extension.reserved_tokens,
+        reserved_minted: AIRDROP_COUNT
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .min(config.extension.reserved_tokens),
+        rate_limit: config.extension.rate_limit,
+        config_frozen: CONFIG_FROZEN.may_load(deps.storage)?.unwrap_or_default(),
+        mint_cancelled: MINT_CANCELLED.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+fn query_resolved_config(deps: Deps) -> StdResult<ResolvedConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let sg721_address = SG721_ADDRESS.load(deps.storage)?;
+    let unique_minter_count = UNIQUE_MINTER_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    let payment_address = config
+        .extension
+        .payment_address
+        .unwrap_or_else(|| config.extension.admin.clone());
+    let payment_splits = config
+        .extension
+        .payment_splits
+        .unwrap_or_else(|| vec![(payment_address.clone(), Decimal::one())]);
+
+    Ok(ResolvedConfigResponse {
+        admin: config.extension.admin.to_string(),
+        nft_data: config.extension.nft_data,
+        payment_address,
+        per_address_limit: config.extension.per_address_limit,
+        num_tokens: config.extension.num_tokens,
+        end_time: config.extension.end_time,
+        sg721_address: sg721_address.to_string(),
+        sg721_code_id: config.collection_code_id,
+        start_time: config.extension.start_time,
+        allowlist_mint_price: config
+            .extension
+            .allowlist_mint_price
+            .unwrap_or_else(|| config.mint_price.clone()),
+        mint_price: config.mint_price,
+        factory: config.factory.to_string(),
+        lifetime_per_address_cap: config
+            .extension
+            .lifetime_per_address_cap
+            .unwrap_or(u32::MAX),
+        unique_minter_count,
+        payment_splits,
+    })
+}
+
+fn query_collection(deps: Deps) -> StdResult<CollectionResponse> {
+    let address = SG721_ADDRESS.may_load(deps.storage)?.ok_or_else(|| {
+        StdError::generic_err("Collection address not set, sg721 instantiate reply not processed")
+    })?;
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(CollectionResponse {
+        address: address.to_string(),
+        code_id: config.collection_code_id,
     })
 }
 
@@ -781,6 +2639,180 @@ pub fn query_status(deps: Deps) -> StdResult<StatusResponse> {
     Ok(StatusResponse { status })
 }
 
+fn query_full_status(deps: Deps) -> StdResult<FullStatusResponse> {
+    let status = STATUS.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Report a failing factory as not allowed rather than failing the whole query
+    let allowed_code_id = deps
+        .querier
+        .query_wasm_smart::<AllowedCollectionCodeIdResponse>(
+            config.factory,
+            &Sg2QueryMsg::AllowedCollectionCodeId(config.collection_code_id),
+        )
+        .map(|res| res.allowed)
+        .unwrap_or(false);
+
+    Ok(FullStatusResponse {
+        status,
+        allowed_code_id,
+    })
+}
+
+fn query_allowlist_allocation(
+    deps: Deps,
+    address: String,
+) -> StdResult<AllowlistAllocationResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    let allocation = ALLOWLIST.may_load(deps.storage, &addr)?.unwrap_or_default();
+    Ok(AllowlistAllocationResponse {
+        address: addr.to_string(),
+        allocation,
+    })
+}
+
+// Always queried fresh from the factory, nothing is cached
+fn query_live_factory_params(deps: Deps) -> StdResult<OpenEditionMinterParams> {
+    let config = CONFIG.load(deps.storage)?;
+    let factory: ParamsResponse = deps
+        .querier
+        .query_wasm_smart(config.factory, &Sg2QueryMsg::Params {})?;
+    Ok(factory.params)
+}
+
+fn query_quote_batch(
+    deps: Deps,
+    env: Env,
+    count: u32,
+    address: Option<String>,
+) -> StdResult<QuoteBatchResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    // Before the public mint only `MintAllowlist` runs, one token at a time
+    if env.block.time < config.extension.start_time {
+        let allocation = match &address {
+            Some(address) => ALLOWLIST
+                .may_load(deps.storage, address)?
+                .unwrap_or_default(),
+            None => 0,
+        };
+        if allocation == 0 {
+            return Err(StdError::generic_err(
+                ContractError::BeforeMintStartTime {}.to_string(),
+            ));
+        }
+        if count != 1 {
+            return Err(StdError::generic_err(
+                "Allowlist mints are quoted one token at a time",
+            ));
+        }
+        let allowlist_price = config
+            .extension
+            .allowlist_mint_price
+            .unwrap_or(config.mint_price);
+        return Ok(QuoteBatchResponse {
+            total: allowlist_price.clone(),
+            per_token: vec![allowlist_price],
+        });
+    }
+
+    if config.extension.cw20_mint_price.is_some() {
+        return Err(StdError::generic_err(
+            ContractError::Cw20PaymentRequired {}.to_string(),
+        ));
+    }
+    let factory_params = query_live_factory_params(deps)?;
+    let mint_count = match &address {
+        Some(address) => MINTER_ADDRS
+            .may_load(deps.storage, address)?
+            .unwrap_or_default(),
+        None => 0,
+    };
+    check_mint_batch(&config, &factory_params, &env, mint_count, count)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let public_price = mint_price_with_params(&config, &factory_params, false);
+    let total = public_price.amount.checked_mul(Uint128::from(count))?;
+    Ok(QuoteBatchResponse {
+        total: coin(total.u128(), &public_price.denom),
+        per_token: vec![public_price; count as usize],
+    })
+}
+
+// Mirrors the fee math and payouts of a single token mint without a referrer, without touching state
+fn query_simulate_mint(
+    deps: Deps,
+    sender: String,
+    is_admin: bool,
+) -> StdResult<SimulateMintResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let sender = deps.api.addr_validate(&sender)?;
+    if is_admin && sender != config.extension.admin {
+        return Err(StdError::generic_err("Sender is not an admin"));
+    }
+
+    let factory_params = query_live_factory_params(deps)?;
+    let mint_price = mint_price_with_params(&config, &factory_params, is_admin);
+    let network_fee = apply_fee(
+        mint_price.amount,
+        mint_fee_rate(deps, &factory_params, is_admin)?,
+    )?;
+    let platform_fee = match (
+        factory_params.extension.platform_fee_bps,
+        &factory_params.extension.platform_fee_address,
+    ) {
+        (Some(platform_fee_bps), Some(_)) if !is_admin => {
+            apply_fee(mint_price.amount, Decimal::bps(platform_fee_bps))?
+        }
+        _ => Uint128::zero(),
+    };
+    let seller_amount = mint_price
+        .amount
+        .checked_sub(network_fee)?
+        .checked_sub(platform_fee)?;
+    let payouts = seller_payouts(&config, seller_amount)
+        .into_iter()
+        .map(|(recipient, amount)| {
+            (
+                recipient.to_string(),
+                coin(amount.u128(), &mint_price.denom),
+            )
+        })
+        .collect();
+
+    Ok(SimulateMintResponse {
+        network_fee: coin(network_fee.u128(), &mint_price.denom),
+        seller_amount: coin(seller_amount.u128(), &mint_price.denom),
+        mint_price,
+        payouts,
+    })
+}
+
+fn query_fee_schedule(deps: Deps) -> StdResult<FeeScheduleResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let factory_params = query_live_factory_params(deps)?;
+    let mint_fee_bps = match factory_params.extension.verified_mint_fee_bps {
+        Some(verified_mint_fee_bps) if STATUS.load(deps.storage)?.is_verified => {
+            verified_mint_fee_bps
+        }
+        _ => factory_params.mint_fee_bps,
+    };
+
+    Ok(FeeScheduleResponse {
+        mint_fee_bps,
+        airdrop_mint_fee_bps: factory_params.extension.airdrop_mint_fee_bps,
+        dev_fee_address: config
+            .extension
+            .dev_fee_address_override
+            .map_or(factory_params.extension.dev_fee_address, String::from),
+        min_mint_price: factory_params.min_mint_price,
+        creation_fee: factory_params.creation_fee,
+    })
+}
+
 fn query_mint_count_per_address(deps: Deps, address: String) -> StdResult<MintCountResponse> {
     let addr = deps.api.addr_validate(&address)?;
     let mint_count = (MINTER_ADDRS.key(&addr).may_load(deps.storage)?).unwrap_or(0);
@@ -790,16 +2822,164 @@ fn query_mint_count_per_address(deps: Deps, address: String) -> StdResult<MintCo
     })
 }
 
+fn query_mint_counts(deps: Deps, addresses: Vec<String>) -> StdResult<Vec<MintCountResponse>> {
+    if addresses.len() > MAX_MINT_COUNTS_ADDRESSES {
+        return Err(StdError::generic_err(format!(
+            "Too many addresses, max {MAX_MINT_COUNTS_ADDRESSES}"
+        )));
+    }
+    addresses
+        .into_iter()
+        .map(|address| query_mint_count_per_address(deps, address))
+        .collect()
+}
+
 fn query_mint_count(deps: Deps) -> StdResult<TotalMintCountResponse> {
     let mint_count = TOTAL_MINT_COUNT.load(deps.storage)?;
     Ok(TotalMintCountResponse { count: mint_count })
 }
 
+fn query_airdrop_queue(deps: Deps) -> StdResult<AirdropQueueResponse> {
+    Ok(AirdropQueueResponse {
+        remaining: AIRDROP_QUEUE_REMAINING
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+// The fee is rounded per token, like each airdrop mint does
+fn query_airdrop_fee(deps: Deps, count: u32) -> StdResult<AirdropFeeResponse> {
+    let factory_params = query_live_factory_params(deps)?;
+    let airdrop_mint_price = factory_params.extension.airdrop_mint_price;
+    let fee = apply_fee(
+        airdrop_mint_price.amount,
+        Decimal::bps(factory_params.extension.airdrop_mint_fee_bps),
+    )?;
+    let total = fee.checked_mul(Uint128::from(count))?;
+    Ok(AirdropFeeResponse {
+        per_token: coin(fee.u128(), &airdrop_mint_price.denom),
+        total: coin(total.u128(), airdrop_mint_price.denom),
+    })
+}
+
+fn query_contract_info(deps: Deps) -> StdResult<ContractInfoResponse> {
+    let version = cw2::get_contract_version(deps.storage)?;
+    Ok(ContractInfoResponse {
+        contract: version.contract,
+        version: version.version,
+        features: FEATURES.iter().map(|feature| feature.to_string()).collect(),
+    })
+}
+
+fn query_can_mint(deps: Deps, env: Env, address: String) -> StdResult<CanMintResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    Ok(match check_can_mint(deps, &env, &address) {
+        Ok(()) => CanMintResponse {
+            can_mint: true,
+            reason: None,
+        },
+        Err(err) => CanMintResponse {
+            can_mint: false,
+            reason: Some(err.to_string()),
+        },
+    })
+}
+
+// Checks of a public mint of a single token, those of `execute_mint_sender` then `_execute_mint`.
+// Enforced by the cw20 mint and reported by `CanMint`. The cw20 payment mode is left out,
+// the mint is still possible through the cw20 hook
+fn check_can_mint(deps: Deps, env: &Env, address: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_public_mint_window(&config, env)?;
+
+    let limit_exempt = config
+        .extension
+        .unlimited_addresses
+        .iter()
+        .flatten()
+        .any(|addr| addr == address);
+    let mint_count = MINTER_ADDRS
+        .may_load(deps.storage, address)?
+        .unwrap_or_default();
+    if !limit_exempt && mint_count >= config.extension.per_address_limit {
+        return Err(ContractError::MaxPerAddressLimitExceeded {});
+    }
+
+    check_mint_gates(deps.storage, &config, address, 1, false)?;
+    check_rate_limit(deps.storage, env, &config, address, 1)?;
+    Ok(())
+}
+
+// Mirrors the metadata `_execute_mint` assigns to the next token index
+fn query_next_token_metadata(deps: Deps) -> StdResult<NextTokenMetadataResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let token_index = TOKEN_INDEX.may_load(deps.storage)?.unwrap_or_default() + 1;
+    let (extension, token_uri) = config.extension.token_metadata(token_index);
+    Ok(NextTokenMetadataResponse {
+        token_id: prefixed_token_id(config.extension.token_id_prefix.as_deref(), token_index),
+        extension,
+        token_uri,
+    })
+}
+
+fn query_token_seed(deps: Deps, token_id: String) -> StdResult<TokenSeedResponse> {
+    let seed = TOKEN_SEEDS.may_load(deps.storage, &token_id)?;
+    Ok(TokenSeedResponse { token_id, seed })
+}
+
+// Token ids keep increasing even if the mint count is ever reduced
+fn query_current_token_index(deps: Deps) -> StdResult<CurrentTokenIndexResponse> {
+    let token_index = TOKEN_INDEX.may_load(deps.storage)?.unwrap_or_default();
+    Ok(CurrentTokenIndexResponse { token_index })
+}
+
+fn query_unlimited_addresses(deps: Deps) -> StdResult<UnlimitedAddressesResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(UnlimitedAddressesResponse {
+        addresses: config.extension.unlimited_addresses.unwrap_or_default(),
+    })
+}
+
 fn query_mintable_num_tokens(deps: Deps) -> StdResult<MintableNumTokensResponse> {
     let count = MINTABLE_NUM_TOKENS.may_load(deps.storage)?;
     Ok(MintableNumTokensResponse { count })
 }
 
+fn query_unique_minter_curve(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<UniqueMinterCurveResponse> {
+    let limit = limit
+        .unwrap_or(PAGINATION_DEFAULT_LIMIT)
+        .min(PAGINATION_MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let points = UNIQUE_MINTER_CHECKPOINTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(block_height, cumulative_unique)| UniqueMinterCheckpoint {
+                block_height,
+                cumulative_unique,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(UniqueMinterCurveResponse { points })
+}
+
+fn query_revenue(deps: Deps) -> StdResult<RevenueResponse> {
+    Ok(RevenueResponse {
+        seller_amount: revenue_coins(deps.storage, TOTAL_SELLER_REVENUE)?,
+        network_fees: revenue_coins(deps.storage, TOTAL_NETWORK_FEES)?,
+    })
+}
+
+fn revenue_coins(storage: &dyn Storage, map: Map<&str, Uint128>) -> StdResult<Vec<Coin>> {
+    map.range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect()
+}
+
 fn query_start_time(deps: Deps) -> StdResult<StartTimeResponse> {
     let config = CONFIG.load(deps.storage)?;
     Ok(StartTimeResponse {
@@ -807,6 +2987,17 @@ fn query_start_time(deps: Deps) -> StdResult<StartTimeResponse> {
     })
 }
 
+fn query_summary(deps: Deps) -> StdResult<SummaryResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(SummaryResponse {
+        start_time: config.extension.start_time,
+        end_time: config.extension.end_time,
+        mint_price: config.mint_price,
+        num_tokens: config.extension.num_tokens,
+        total_minted: TOTAL_MINT_COUNT.load(deps.storage)?,
+    })
+}
+
 fn query_end_time(deps: Deps) -> StdResult<EndTimeResponse> {
     let config = CONFIG.load(deps.storage)?;
     let end_time_response = config
@@ -820,6 +3011,31 @@ fn query_end_time(deps: Deps) -> StdResult<EndTimeResponse> {
     Ok(end_time_response)
 }
 
+fn query_time_remaining(deps: Deps, env: Env) -> StdResult<TimeRemainingResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let now = env.block.time.seconds();
+    Ok(TimeRemainingResponse {
+        seconds_until_start: config.extension.start_time.seconds().saturating_sub(now),
+        seconds_until_end: config
+            .extension
+            .end_time
+            .map(|end_time| end_time.seconds().saturating_sub(now)),
+    })
+}
+
+fn query_shuffle_seed(deps: Deps, env: Env) -> StdResult<ShuffleSeedResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let ended = config
+        .extension
+        .end_time
+        .is_some_and(|end_time| env.block.time >= end_time);
+    let sold_out = MINTABLE_NUM_TOKENS.may_load(deps.storage)? == Some(0);
+    // Disclosing the seed earlier would reveal which entry the next mints get
+    Ok(ShuffleSeedResponse {
+        shuffle_seed: config.extension.shuffle_seed.filter(|_| ended || sold_out),
+    })
+}
+
 fn query_mint_price(deps: Deps) -> StdResult<MintPriceResponse> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -838,13 +3054,30 @@ fn query_mint_price(deps: Deps) -> StdResult<MintPriceResponse> {
     Ok(MintPriceResponse {
         public_price,
         airdrop_price,
+        display_price: display_price(deps, &current_price),
         current_price,
     })
 }
 
+// Formats the price in the denom's display unit, the raw coin when the denom has no usable metadata
+fn display_price(deps: Deps, price: &Coin) -> String {
+    let Ok(metadata) = deps.querier.query_denom_metadata(&price.denom) else {
+        return price.to_string();
+    };
+    metadata
+        .denom_units
+        .iter()
+        .find(|unit| !metadata.display.is_empty() && unit.denom == metadata.display)
+        .and_then(|unit| Decimal::from_atomics(price.amount, unit.exponent).ok())
+        .map_or_else(
+            || price.to_string(),
+            |amount| format!("{} {}", amount, metadata.display),
+        )
+}
+
 // Reply callback triggered from cw721 contract instantiation
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.id != INSTANTIATE_SG721_REPLY_ID {
         return Err(ContractError::InvalidReplyID {});
     }
@@ -854,16 +3087,21 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         Ok(res) => {
             let sg721_address = res.contract_address;
             SG721_ADDRESS.save(deps.storage, &Addr::unchecked(sg721_address.clone()))?;
+            // Typed event so indexers can map each minter to its collection
+            let event = Event::new("collection-instantiated")
+                .add_attribute("minter", env.contract.address.to_string())
+                .add_attribute("sg721_address", sg721_address.clone());
             Ok(Response::default()
                 .add_attribute("action", "instantiate_sg721_reply")
-                .add_attribute("sg721_address", sg721_address))
+                .add_attribute("sg721_address", sg721_address)
+                .add_event(event))
         }
-        Err(_) => Err(ContractError::InstantiateSg721Error {}),
+        Err(err) => Err(ContractError::InstantiateSg721Error(err.to_string())),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let current_version = cw2::get_contract_version(deps.storage)?;
     if current_version.contract != CONTRACT_NAME {
         return Err(StdError::generic_err("Cannot upgrade to a different contract").into());
@@ -884,7 +3122,79 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Ok(Response::new());
     }
 
+    let mut res = Response::new();
+
+    // Bring the stored state forward one release at a time
+    if version < Version::new(3, 6, 0) {
+        res = crate::upgrades::v3_6_0::upgrade(deps.branch(), &env, res)?;
+    }
+
+    match msg {
+        MigrateMsg::Noop {} => {}
+        MigrateMsg::ToOnChainMetadata { extension, force } => {
+            migrate_to_on_chain_metadata(deps.branch(), extension, force.unwrap_or(false))?;
+            res = res.add_attribute("nft_data_type", "on_chain_metadata");
+        }
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::new())
+    Ok(res)
+}
+
+// Already minted tokens keep their metadata, only future mints use the extension
+fn migrate_to_on_chain_metadata(
+    deps: DepsMut,
+    mut extension: Metadata,
+    force: bool,
+) -> Result<(), ContractError> {
+    if !force && TOTAL_MINT_COUNT.load(deps.storage)? > 0 {
+        return Err(ContractError::MetadataMigrationAfterMint {});
+    }
+    // Only a collection storing a `Metadata` extension can take the new mint messages
+    let collection = cw2::query_contract_info(&deps.querier, SG721_ADDRESS.load(deps.storage)?)?;
+    if collection.contract != ON_CHAIN_METADATA_COLLECTION {
+        return Err(ContractError::CollectionNotOnChainMetadata {});
+    }
+    extension.image = extension
+        .image
+        .as_ref()
+        .map(|img| Url::parse(img.trim()))
+        .transpose()?
+        .map(|url| url.to_string());
+
+    let mut config = CONFIG.load(deps.storage)?;
+    config.extension.nft_data = NftData {
+        nft_data_type: NftMetadataType::OnChainMetadata,
+        extension: Some(extension),
+        token_uri: None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{SubMsgResponse, SubMsgResult};
+
+    #[test]
+    fn check_reply_surfaces_parse_error() {
+        let mut deps = mock_dependencies();
+        // The instantiate response carries no data to read the collection address from
+        let msg = Reply {
+            id: INSTANTIATE_SG721_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        };
+        let err = reply(deps.as_mut(), mock_env(), msg).unwrap_err();
+        let ContractError::InstantiateSg721Error(reason) = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert!(reason.ends_with("Missing reply data"));
+        assert!(SG721_ADDRESS.may_load(&deps.storage).unwrap().is_none());
+    }
 }
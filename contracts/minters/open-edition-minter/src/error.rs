@@ -46,10 +46,42 @@ pub enum ContractError {
     #[error("Sold out")]
     SoldOut {},
 
+    #[error("No recipients provided")]
+    EmptyRecipients {},
+
+    #[error("Batch too large, max: {max}")]
+    BatchTooLarge { max: u32 },
+
+    #[error("Airdrop limit reached")]
+    AirdropLimitReached {},
+
+    #[error("Airdrop queue is empty")]
+    AirdropQueueEmpty {},
+
+    #[error("Airdrop would exceed the max airdrop fee spend")]
+    AirdropFeeCapReached {},
+
+    #[error("The team reserve has not been minted yet")]
+    ReserveNotMinted {},
+
+    #[error("Reserved tokens can't exceed the number of tokens or the airdrop limit")]
+    InvalidReservedTokens {},
+
+    #[error("Rate limit needs at least one mint within a window of at least one second")]
+    InvalidRateLimit {},
+
+    #[error("Rate limited, retry after {retry_after} seconds")]
+    RateLimited { retry_after: u64 },
+
+    #[error("Sender can't refer themselves")]
+    SelfReferral {},
+
     #[error("Not Sold out")]
     NotSoldOut {},
 
-    #[error("MintingHasNotYetEnded")]
+    #[error(
+        "MintingHasNotYetEnded: purge is only possible after the end time plus the purge delay"
+    )]
     MintingHasNotYetEnded {},
 
     #[error("InvalidDenom {expected} got {got}")]
@@ -64,6 +96,9 @@ pub enum ContractError {
     #[error("Update price {updated} higher than allowed price {allowed}")]
     UpdatedMintPriceTooHigh { allowed: u128, updated: u128 },
 
+    #[error("Payment splits must not be empty and their shares must sum to 1")]
+    InvalidPaymentSplits {},
+
     #[error("Invalid address {addr}")]
     InvalidAddress { addr: String },
 
@@ -88,15 +123,90 @@ pub enum ContractError {
     #[error("InvalidStartTradingTime {0} > {1}")]
     InvalidStartTradingTime(Timestamp, Timestamp),
 
-    #[error("Instantiate sg721 error")]
-    InstantiateSg721Error {},
+    #[error("Start trading time can only be based on the end time if the mint has one")]
+    EndBasedTradingWithoutEndTime {},
+
+    #[error("Trading already started at {0}")]
+    TradingAlreadyStarted(Timestamp),
+
+    #[error("Failed to query params from factory {factory}")]
+    FactoryQueryFailed { factory: String },
+
+    #[error("Instantiate sg721 error: {0}")]
+    InstantiateSg721Error(String),
 
     #[error("Invalid base token URI (must be an IPFS URI)")]
     InvalidBaseTokenURI {},
 
+    #[error("Mint has not ended, use UpdateEndTime instead")]
+    MintNotEnded {},
+
+    #[error("Mint was force ended by governance and can't be reopened")]
+    MintForceEnded {},
+
+    #[error("Tokens were already minted, pass force to convert the metadata type")]
+    MetadataMigrationAfterMint {},
+
+    #[error("The collection does not store on-chain metadata")]
+    CollectionNotOnChainMetadata {},
+
+    #[error("Sg721 address is already set")]
+    Sg721AddressAlreadySet {},
+
+    #[error("Proceeds contract must be an instantiated contract")]
+    InvalidProceedsContract {},
+
+    #[error("Minter is blocked")]
+    MinterBlocked {},
+
+    #[error("Mint configuration is frozen")]
+    ConfigFrozen {},
+
+    #[error("Mint was cancelled")]
+    MintCancelled {},
+
+    #[error("Collection name too long")]
+    CollectionNameTooLong {},
+
+    #[error("Royalty share cannot be greater than 10%")]
+    InvalidRoyalties {},
+
+    #[error("Description too long")]
+    DescriptionTooLong {},
+
+    #[error("Collection code id {0} is not allowed by the factory")]
+    CollectionCodeIdNotAllowed(u64),
+
+    #[error("The minter is not the admin of its collection")]
+    NotCollectionAdmin {},
+
+    #[error("Token id prefix must be 1 to 32 letters, digits or dashes")]
+    InvalidTokenIdPrefix {},
+
     #[error("address not on whitelist: {addr}")]
     NotWhitelisted { addr: String },
 
+    #[error("Sender doesn't hold any of the allowed collections")]
+    NotAHolder {},
+
+    #[error("Holder free mint already claimed")]
+    HolderAlreadyClaimed {},
+
+    #[error("Allowlist allocation exhausted")]
+    AllowlistExhausted {},
+
+    #[error("Allowlist minting is only possible before the start time")]
+    AllowlistMintEnded {},
+
+    #[error("Whitelist minting is only possible before the start time")]
+    WhitelistMintEnded {},
+
+    #[error("WhitelistNotActive")]
+    WhitelistNotActive {},
+
+    #[error("Whitelist contract returned an unexpected response")]
+    WhitelistQueryFailed {},
+
     #[error("Minting has not started yet")]
     BeforeMintStartTime {},
 
@@ -106,12 +216,36 @@ pub enum ContractError {
     #[error("Minting has ended")]
     AfterMintEndTime {},
 
+    #[error("Per transaction limit must be at least 1")]
+    InvalidPerTxLimit {},
+
+    #[error("Per transaction mint limit exceeded, max: {max}")]
+    PerTxLimitExceeded { max: u32 },
+
+    #[error("Invalid cw20 mint price, must be a non zero amount of a cw20 token")]
+    InvalidCw20MintPrice {},
+
+    #[error("Mint is not paid in cw20 tokens")]
+    Cw20PaymentDisabled {},
+
+    #[error("Mint is paid in cw20 tokens, send them to the minter through the cw20 contract")]
+    Cw20PaymentRequired {},
+
+    #[error("Invalid cw20 token: {0}")]
+    InvalidCw20Token(String),
+
+    #[error("Incorrect cw20 payment amount, got: {got}, expected: {expected}")]
+    IncorrectCw20PaymentAmount { got: Uint128, expected: Uint128 },
+
     #[error("Invalid minting limit per address. max: {max}, min: 1, got: {got}")]
     InvalidPerAddressLimit { max: u32, min: u32, got: u32 },
 
     #[error("Max minting limit per address exceeded")]
     MaxPerAddressLimitExceeded {},
 
+    #[error("Lifetime minting limit per address exceeded")]
+    LifetimeLimitExceeded {},
+
     #[error("Token id: {token_id} already sold")]
     TokenIdAlreadySold { token_id: u32 },
 
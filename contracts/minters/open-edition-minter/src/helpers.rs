@@ -1,11 +1,13 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Addr, Coin, ContractInfoResponse, CosmosMsg, CustomQuery, Empty, Querier,
-    QuerierWrapper, StdError, StdResult, WasmMsg, WasmQuery,
+    to_json_binary, Addr, Coin, ContractInfoResponse, CosmosMsg, CustomQuery, Decimal, Empty,
+    HexBinary, Querier, QuerierWrapper, StdError, StdResult, Timestamp, Uint128, Uint256, WasmMsg,
+    WasmQuery,
 };
 use cw721_base::Extension;
 use sg721::ExecuteMsg as Sg721ExecuteMsg;
 use sg_metadata::Metadata;
+use sha2::{Digest, Sha256};
 
 use crate::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
 
@@ -63,6 +65,14 @@ impl MinterContract {
     }
 }
 
+// Token ids are the bare index unless the collection configured a prefix
+pub fn prefixed_token_id(prefix: Option<&str>, token_index: u64) -> String {
+    match prefix {
+        Some(prefix) => format!("{}-{}", prefix, token_index),
+        None => token_index.to_string(),
+    }
+}
+
 pub fn mint_nft_msg(
     sg721_address: Addr,
     token_id: String,
@@ -95,3 +105,99 @@ pub fn mint_nft_msg(
     };
     Ok(mint_msg)
 }
+
+/// Fee taken on `amount` at `rate`, rounded half up. The seller amount is always derived by
+/// subtracting this fee so both sides add up to the price
+pub fn apply_fee(amount: Uint128, rate: Decimal) -> StdResult<Uint128> {
+    let fractional = Uint256::from(Decimal::one().atomics());
+    let fee = (amount.full_mul(rate.atomics()) + fractional / Uint256::from(2u8)) / fractional;
+    Ok(Uint128::try_from(fee)?)
+}
+
+/// Seed of a token minted without a randomness provider, a hash of the block time and token id
+pub fn fallback_token_seed(time: Timestamp, token_id: &str) -> HexBinary {
+    let hash = Sha256::digest([&time.nanos().to_be_bytes()[..], token_id.as_bytes()].concat());
+    HexBinary::from(hash.as_slice())
+}
+
+/// Position in a pool of `pool_len` entries assigned to `position`, keyed by `seed`.
+/// A small Feistel network walked until it lands inside the pool, so distinct positions
+/// below `pool_len` never share an entry and anyone knowing the seed can recompute the order.
+pub fn shuffled_pool_index(seed: u64, position: u64, pool_len: u64) -> u64 {
+    let bits = u64::BITS - pool_len.saturating_sub(1).leading_zeros();
+    let half_bits = bits.div_ceil(2);
+    let mask = (1u64 << half_bits) - 1;
+
+    let mut index = position % pool_len;
+    loop {
+        let (mut left, mut right) = (index >> half_bits, index & mask);
+        for round in 0..4u8 {
+            let hash = Sha256::digest(
+                [
+                    &seed.to_be_bytes()[..],
+                    &[round][..],
+                    &right.to_be_bytes()[..],
+                ]
+                .concat(),
+            );
+            let round_key = u64::from_be_bytes(hash[0..8].try_into().unwrap()) & mask;
+            (left, right) = (right, left ^ round_key);
+        }
+        index = (left << half_bits) | right;
+        if index < pool_len {
+            return index;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_apply_fee_rounding() {
+        let rate = Decimal::bps(1_000);
+        assert_eq!(
+            apply_fee(Uint128::new(100), rate).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            apply_fee(Uint128::new(104), rate).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            apply_fee(Uint128::new(105), rate).unwrap(),
+            Uint128::new(11)
+        );
+        assert_eq!(
+            apply_fee(Uint128::new(1), Decimal::bps(5_000)).unwrap(),
+            Uint128::new(1)
+        );
+        assert_eq!(
+            apply_fee(Uint128::MAX, Decimal::one()).unwrap(),
+            Uint128::MAX
+        );
+    }
+
+    #[test]
+    fn check_apply_fee_reconciles() {
+        // Deterministic pseudo random prices and rates, the contract has no rng dependency
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let price = Uint128::new((state % 1_000_000_000_000) as u128);
+            let rate = Decimal::bps(state % 10_001);
+
+            let network_fee = apply_fee(price, rate).unwrap();
+            let seller_amount = price.checked_sub(network_fee).unwrap();
+            assert_eq!(seller_amount + network_fee, price);
+            // Never more than half a unit away from the exact fee
+            let exact = Decimal::from_ratio(price, 1u128) * rate;
+            assert!(
+                Decimal::from_ratio(network_fee, 1u128).abs_diff(exact) <= Decimal::percent(50)
+            );
+        }
+    }
+}
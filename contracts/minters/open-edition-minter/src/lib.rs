@@ -6,4 +6,5 @@ pub mod msg;
 
 pub mod helpers;
 pub mod state;
+pub mod upgrades;
 pub mod validation;
@@ -1,8 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use cosmwasm_std::{Addr, Coin, Decimal, HexBinary, Timestamp, Uint128};
+use cw20::{Cw20CoinVerified, Cw20ReceiveMsg};
 
 use open_edition_factory::types::NftData;
 use open_edition_factory::{msg::OpenEditionMinterCreateMsg, state::OpenEditionMinterParams};
+use sg4::Status;
+use sg_metadata::Metadata;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -19,15 +22,124 @@ pub enum ExecuteMsg {
     },
     UpdateStartTime(Timestamp),
     UpdateEndTime(Timestamp),
+    /// Sets a new end time on a mint that has already ended, unless governance force ended it
+    ReopenMint {
+        new_end_time: Timestamp,
+    },
     /// Runs custom checks against TradingStartTime on VendingMinter, then updates by calling sg721-base
     UpdateStartTradingTime(Option<Timestamp>),
+    /// Updates the collection description and image through the minter, unset fields are kept
+    UpdateCollectionInfo {
+        description: Option<String>,
+        image: Option<String>,
+    },
     UpdatePerAddressLimit {
         per_address_limit: u32,
     },
     MintTo {
         recipient: String,
     },
+    /// Public mint paying `referral_bps` of the seller amount to the referrer
+    MintWithReferral {
+        referrer: String,
+    },
+    /// Public mint delivered to `recipient`, counted against the sender's limit
+    MintFor {
+        recipient: String,
+    },
+    /// Public mint of `count` tokens to the sender, bounded by the factory's max batch size
+    MintBatch {
+        count: u32,
+        /// Mints what is left of the supply and refunds the rest instead of failing, off by default
+        best_effort: Option<bool>,
+    },
+    /// Admin airdrop minting one token to each recipient, all or nothing
+    MintToBatch {
+        recipients: Vec<String>,
+    },
+    /// Admin airdrop minting the given number of tokens to each recipient
+    MintToWeighted {
+        recipients: Vec<(String, u32)>,
+    },
     BurnRemaining {},
+    /// Sets the allowlist allocation of each address, 0 removes the address
+    SetAllowlist {
+        entries: Vec<(String, u32)>,
+    },
+    /// Mint before the start time using the sender's allowlist allocation
+    MintAllowlist {},
+    /// Sets the whitelist contract gating the presale, only before the start time
+    SetWhitelist {
+        address: String,
+    },
+    /// Mint before the start time at the whitelist price, within its per address limit
+    MintWhitelist {},
+    /// Free mint for holders of an allowed collection, once per holder
+    MintAsHolder {},
+    /// Permanently disables the admin updates of the mint, minting is unaffected
+    FreezeConfig {},
+    /// Replaces the addresses exempt from the per address limit, an empty list clears it
+    SetUnlimitedAddresses {
+        addresses: Vec<String>,
+    },
+    /// Cancels a mint that has not started, terminal. Queued airdrops are dropped and their
+    /// escrow refunded to the admin, other funds held by the minter stay where they are
+    CancelMint {},
+    /// Queues airdrops paid upfront at the airdrop price, minted later through `ProcessAirdrop`
+    QueueAirdrop {
+        recipients: Vec<String>,
+    },
+    /// Mints up to `limit` queued airdrops in order, anyone can call it
+    ProcessAirdrop {
+        limit: u32,
+    },
+    /// Drops up to `limit` of the oldest queued airdrops and refunds their escrow to the admin,
+    /// for entries `ProcessAirdrop` can no longer mint
+    DequeueAirdrop {
+        limit: u32,
+    },
+    /// Caps the network fees spent on airdrops over the mint's lifetime, None removes the cap
+    UpdateMaxAirdropFeeSpend {
+        max_airdrop_fee_spend: Option<Uint128>,
+    },
+    /// Mint paid in the cw20 of `cw20_mint_price`, the sent `msg` is a `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+}
+
+/// Actions triggered by sending cw20 tokens to the minter
+#[cw_serde]
+pub enum ReceiveMsg {
+    Mint {},
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    /// Only runs the state upgrades between the stored and the new version
+    Noop {},
+    /// Switches future mints to on-chain metadata seeded with this extension
+    ToOnChainMetadata {
+        extension: Metadata,
+        /// Converts even if tokens were already minted, those keep their metadata
+        force: Option<bool>,
+    },
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    UpdateStatus {
+        is_verified: bool,
+        is_blocked: bool,
+        is_explicit: bool,
+    },
+    /// Ends the mint at the current block time, the admin can't reopen it afterwards
+    ForceEndMint {},
+    /// Sets the collection address of a minter left without one, only when unset
+    SetSg721Address { address: String },
+    /// Sets the per address limit without the factory's max_per_address_limit cap
+    OverridePerAddressLimit { limit: u32 },
+    /// Migrates the collection to a code id allowed by the factory. Only works when the minter is
+    /// the collection's admin, collections from earlier minter releases have the creator as admin
+    MigrateCollection { new_code_id: u64 },
 }
 
 #[cw_serde]
@@ -36,10 +148,85 @@ pub enum QueryMsg {
     StartTime {},
     EndTime {},
     MintPrice {},
-    MintCount { address: String },
+    MintCount {
+        address: String,
+    },
+    /// Mint counts of up to 100 addresses, in the order given
+    MintCounts {
+        addresses: Vec<String>,
+    },
     TotalMintCount {},
     Status {},
     MintableNumTokens {},
+    UniqueMinterCurve {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Same as `Config` but with unset optional values replaced by the defaults the minter applies
+    ResolvedConfig {},
+    /// Accumulated seller revenue and network fees, per denom
+    Revenue {},
+    /// Local status along with the factory's view on the collection code id
+    FullStatus {},
+    AllowlistAllocation {
+        address: String,
+    },
+    /// Lightweight lookup of the sg721 collection
+    Collection {},
+    /// Funds to attach to a `MintBatch` of `count` tokens from `address`. Before the start time
+    /// quotes the single token `MintAllowlist` instead, when `address` is on the allowlist
+    QuoteBatch {
+        count: u32,
+        address: Option<String>,
+    },
+    /// Live factory params the minter operates under
+    FactoryParams {},
+    /// Seconds left until the mint starts and ends, relative to the current block time
+    TimeRemaining {},
+    /// Seed of the extension pool shuffle, only disclosed once the mint has ended
+    ShuffleSeed {},
+    /// Fee breakdown of a single token mint by `sender` without a referrer, nothing is executed
+    SimulateMint {
+        sender: String,
+        is_admin: bool,
+    },
+    /// Fees the minter currently applies, resolved from the live factory params
+    FeeSchedule {},
+    /// Index of the last minted token, 0 before the first mint
+    CurrentTokenIndex {},
+    /// Addresses exempt from the per address limit on public mints
+    UnlimitedAddresses {},
+    /// Start, end and price in a single call, for listing a factory's minters
+    Summary {},
+    /// Token id and metadata the next mint would assign
+    NextTokenMetadata {},
+    /// Number of queued airdrops not processed yet
+    AirdropQueue {},
+    /// Random seed embedded as a trait in a minted token's on-chain metadata
+    TokenSeed {
+        token_id: String,
+    },
+    /// Network fee owed for airdropping `count` tokens at the factory's airdrop price
+    AirdropFee {
+        count: u32,
+    },
+    /// Whether a public `Mint` by `address` would pass the minter checks at the current block
+    CanMint {
+        address: String,
+    },
+    /// cw2 name and version of the minter along with the features this build supports
+    ContractInfo {},
+}
+
+/// Query a randomness provider has to answer, once per on-chain metadata mint
+#[cw_serde]
+pub enum RandomnessProviderQueryMsg {
+    RandomSeed { token_id: String },
+}
+
+#[cw_serde]
+pub struct RandomSeedResponse {
+    pub seed: HexBinary,
 }
 
 #[cw_serde]
@@ -55,6 +242,110 @@ pub struct ConfigResponse {
     pub start_time: Timestamp,
     pub mint_price: Coin,
     pub factory: String,
+    pub lifetime_per_address_cap: Option<u32>,
+    pub payment_splits: Option<Vec<(Addr, Decimal)>>,
+    pub allowlist_mint_price: Option<Coin>,
+    pub max_airdrop_tokens: Option<u32>,
+    pub airdrop_count: u32,
+    pub referral_bps: u64,
+    pub purge_delay_secs: u64,
+    pub start_height: Option<u64>,
+    pub extension_pool: Vec<Metadata>,
+    pub whitelist: Option<String>,
+    pub limit_overridden: bool,
+    pub allowed_holder_collections: Option<Vec<Addr>>,
+    pub token_id_prefix: Option<String>,
+    pub config_frozen: bool,
+    pub mint_cancelled: bool,
+    pub proceeds_contract: Option<Addr>,
+    pub dev_fee_address_override: Option<Addr>,
+    pub per_tx_limit: Option<u32>,
+    pub cw20_mint_price: Option<Cw20CoinVerified>,
+    pub randomness_provider: Option<Addr>,
+    pub allow_airdrop_after_end: bool,
+    pub free_mint: bool,
+    pub reserved_tokens: u32,
+    /// Admin mints counted towards the reserve so far
+    pub reserved_minted: u32,
+    pub rate_limit: Option<(u32, u64)>,
+}
+
+#[cw_serde]
+pub struct ResolvedConfigResponse {
+    pub admin: String,
+    pub nft_data: NftData,
+    /// Defaults to the admin address
+    pub payment_address: Addr,
+    pub per_address_limit: u32,
+    /// None means the edition has no token limit
+    pub num_tokens: Option<u32>,
+    /// None means the mint never ends
+    pub end_time: Option<Timestamp>,
+    pub sg721_address: String,
+    pub sg721_code_id: u64,
+    pub start_time: Timestamp,
+    pub mint_price: Coin,
+    pub factory: String,
+    /// Defaults to `u32::MAX`, i.e. no lifetime cap
+    pub lifetime_per_address_cap: u32,
+    /// Defaults to 0 on minters instantiated before unique minters were tracked
+    pub unique_minter_count: u32,
+    /// Defaults to the whole seller amount going to `payment_address`
+    pub payment_splits: Vec<(Addr, Decimal)>,
+    /// Defaults to `mint_price`
+    pub allowlist_mint_price: Coin,
+}
+
+#[cw_serde]
+pub struct RevenueResponse {
+    pub seller_amount: Vec<Coin>,
+    pub network_fees: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct FullStatusResponse {
+    pub status: Status,
+    /// False when the code id was removed from the factory or the factory can't be queried
+    pub allowed_code_id: bool,
+}
+
+#[cw_serde]
+pub struct QuoteBatchResponse {
+    pub total: Coin,
+    pub per_token: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct SimulateMintResponse {
+    pub mint_price: Coin,
+    pub network_fee: Coin,
+    pub seller_amount: Coin,
+    /// Seller amount as it is paid out, to the proceeds contract, each payment split or the
+    /// payment address
+    pub payouts: Vec<(String, Coin)>,
+}
+
+#[cw_serde]
+pub struct FeeScheduleResponse {
+    /// Public mint fee, the verified fee applies once the minter is verified
+    pub mint_fee_bps: u64,
+    pub airdrop_mint_fee_bps: u64,
+    /// The minter's dev fee address override when set
+    pub dev_fee_address: String,
+    pub min_mint_price: Coin,
+    pub creation_fee: Coin,
+}
+
+#[cw_serde]
+pub struct AllowlistAllocationResponse {
+    pub address: String,
+    pub allocation: u32,
+}
+
+#[cw_serde]
+pub struct CollectionResponse {
+    pub address: String,
+    pub code_id: u64,
 }
 
 #[cw_serde]
@@ -72,11 +363,27 @@ pub struct EndTimeResponse {
     pub end_time: Option<String>,
 }
 
+#[cw_serde]
+pub struct TimeRemainingResponse {
+    /// 0 once the mint has started
+    pub seconds_until_start: u64,
+    /// 0 once the mint has ended, None when the mint never ends
+    pub seconds_until_end: Option<u64>,
+}
+
+#[cw_serde]
+pub struct ShuffleSeedResponse {
+    pub shuffle_seed: Option<u64>,
+}
+
 #[cw_serde]
 pub struct MintPriceResponse {
     pub public_price: Coin,
     pub airdrop_price: Coin,
     pub current_price: Coin,
+    /// `current_price` in the denom's display unit from the bank metadata, e.g. `1.5 stars`,
+    /// the raw coin when the denom has no metadata
+    pub display_price: String,
 }
 
 #[cw_serde]
@@ -89,3 +396,75 @@ pub struct MintCountResponse {
 pub struct TotalMintCountResponse {
     pub count: u32,
 }
+
+#[cw_serde]
+pub struct CurrentTokenIndexResponse {
+    pub token_index: u64,
+}
+
+#[cw_serde]
+pub struct UnlimitedAddressesResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct AirdropQueueResponse {
+    pub remaining: u32,
+}
+
+#[cw_serde]
+pub struct CanMintResponse {
+    pub can_mint: bool,
+    /// Error the mint would fail with, None when it can mint
+    pub reason: Option<String>,
+}
+
+#[cw_serde]
+pub struct ContractInfoResponse {
+    pub contract: String,
+    pub version: String,
+    /// Capabilities of this build, for clients gating features by minter version
+    pub features: Vec<String>,
+}
+
+#[cw_serde]
+pub struct AirdropFeeResponse {
+    pub per_token: Coin,
+    pub total: Coin,
+}
+
+#[cw_serde]
+pub struct TokenSeedResponse {
+    pub token_id: String,
+    /// None for tokens without on-chain metadata or not minted yet
+    pub seed: Option<HexBinary>,
+}
+
+#[cw_serde]
+pub struct NextTokenMetadataResponse {
+    pub token_id: String,
+    /// Set for on-chain metadata minters, without the seed trait added at mint time
+    pub extension: Option<Metadata>,
+    /// Set for off-chain metadata minters
+    pub token_uri: Option<String>,
+}
+
+#[cw_serde]
+pub struct SummaryResponse {
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub mint_price: Coin,
+    pub num_tokens: Option<u32>,
+    pub total_minted: u32,
+}
+
+#[cw_serde]
+pub struct UniqueMinterCheckpoint {
+    pub block_height: u64,
+    pub cumulative_unique: u32,
+}
+
+#[cw_serde]
+pub struct UniqueMinterCurveResponse {
+    pub points: Vec<UniqueMinterCheckpoint>,
+}
@@ -1,9 +1,13 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdResult, Storage, Timestamp};
+use cosmwasm_std::{Addr, Coin, Decimal, HexBinary, StdResult, Storage, Timestamp, Uint128};
+use cw20::Cw20CoinVerified;
 use cw_storage_plus::{Item, Map};
 
-use open_edition_factory::types::NftData;
+use open_edition_factory::types::{NftData, NftMetadataType};
 use sg4::{MinterConfig, Status};
+use sg_metadata::Metadata;
+
+use crate::helpers::shuffled_pool_index;
 
 #[cw_serde]
 pub struct ConfigExtension {
@@ -14,7 +18,72 @@ pub struct ConfigExtension {
     pub end_time: Option<Timestamp>,
     pub per_address_limit: u32,
     pub num_tokens: Option<u32>,
+    pub lifetime_per_address_cap: Option<u32>,
+    pub payment_splits: Option<Vec<(Addr, Decimal)>>,
+    pub allowlist_mint_price: Option<Coin>,
+    pub max_airdrop_tokens: Option<u32>,
+    /// Share of the seller amount paid to referrers, 0 disables referrals
+    pub referral_bps: u64,
+    /// Seconds after the end time before anyone but the admin can purge
+    pub purge_delay_secs: u64,
+    /// Block height the public mint opens at, on top of the start time
+    pub start_height: Option<u64>,
+    /// On-chain metadata cycled through by sequential mints, empty uses `nft_data.extension`
+    pub extension_pool: Vec<Metadata>,
+    /// Shuffles the order `extension_pool` entries are assigned in
+    pub shuffle_seed: Option<u64>,
+    /// Whitelist contract gating the presale before the start time
+    pub whitelist: Option<Addr>,
+    /// Holders of these collections can claim one free mint each
+    pub allowed_holder_collections: Option<Vec<Addr>>,
+    /// Prepended to minted token ids, bare indexes when unset
+    pub token_id_prefix: Option<String>,
+    /// Contract receiving the seller amount, takes precedence over payment address and splits
+    pub proceeds_contract: Option<Addr>,
+    /// Public mints from these addresses skip the per address limit
+    pub unlimited_addresses: Option<Vec<Addr>>,
+    /// Receives the dev share of the mint fee instead of the factory's dev fee address
+    pub dev_fee_address_override: Option<Addr>,
+    /// Maximum number of tokens a single batch mint can mint
+    pub per_tx_limit: Option<u32>,
+    /// Price of a public mint in a cw20 token, native public mints are rejected when set
+    pub cw20_mint_price: Option<Cw20CoinVerified>,
+    /// Queried for the random seed of each on-chain metadata mint
+    pub randomness_provider: Option<Addr>,
+    /// MintTo keeps working after end_time, public mints still close
+    pub allow_airdrop_after_end: bool,
+    /// The mint price is exempt from the factory's min_mint_price and can be zero
+    pub free_mint: bool,
+    /// Admin mints required before any other mint, counted with the airdrops
+    pub reserved_tokens: u32,
+    /// At most N public mints per address within any window of M seconds
+    pub rate_limit: Option<(u32, u64)>,
 }
+
+impl ConfigExtension {
+    /// On-chain metadata of the token minted at `token_index`, token indexes start at 1
+    pub fn token_extension(&self, token_index: u64) -> Option<Metadata> {
+        if self.extension_pool.is_empty() {
+            return self.nft_data.extension.clone();
+        }
+        let pool_len = self.extension_pool.len() as u64;
+        let position = (token_index - 1) % pool_len;
+        let pool_index = match self.shuffle_seed {
+            Some(seed) => shuffled_pool_index(seed, position, pool_len),
+            None => position,
+        };
+        Some(self.extension_pool[pool_index as usize].clone())
+    }
+
+    /// Extension and token uri of the token minted at `token_index`, depending on the metadata type
+    pub fn token_metadata(&self, token_index: u64) -> (Option<Metadata>, Option<String>) {
+        match self.nft_data.nft_data_type {
+            NftMetadataType::OnChainMetadata => (self.token_extension(token_index), None),
+            NftMetadataType::OffChainMetadata => (None, self.nft_data.token_uri.clone()),
+        }
+    }
+}
+
 pub type Config = MinterConfig<ConfigExtension>;
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -32,8 +101,173 @@ pub const STATUS: Item<Status> = Item::new("status");
 /// This keeps track of the token index for the token_ids
 pub const TOKEN_INDEX: Item<u64> = Item::new("token_index");
 
+/// This keeps track of the number of unique addresses that minted
+pub const UNIQUE_MINTER_COUNT: Item<u32> = Item::new("unique_minter_count");
+
+/// Cumulative unique minters keyed by block height.
+/// Only blocks in which a new address minted get a checkpoint.
+pub const UNIQUE_MINTER_CHECKPOINTS: Map<u64, u32> = Map::new("umc");
+
+/// Number of tokens minted by the admin
+pub const AIRDROP_COUNT: Item<u32> = Item::new("airdrop_count");
+
+/// Holders that already claimed their free mint
+pub const HOLDER_CLAIMED: Map<&Addr, bool> = Map::new("hc");
+
+/// Set when governance raised the per address limit past the factory max
+pub const PER_ADDRESS_LIMIT_OVERRIDDEN: Item<bool> = Item::new("palo");
+
+/// Set once the admin froze the mint configuration, never unset
+pub const CONFIG_FROZEN: Item<bool> = Item::new("cf");
+
+/// Set once the admin cancelled the mint before it started, never unset
+pub const MINT_CANCELLED: Item<bool> = Item::new("mc");
+
+/// Set once governance force ended the mint, never unset
+pub const MINT_FORCE_ENDED: Item<bool> = Item::new("mfe");
+
+/// Airdrops queued by the admin along with the airdrop price escrowed for each, minted in order
+pub const AIRDROP_QUEUE: Map<u64, (Addr, Coin)> = Map::new("aq");
+
+/// Key of the next queued airdrop
+pub const AIRDROP_QUEUE_SEQ: Item<u64> = Item::new("aqs");
+
+/// Number of airdrops still queued
+pub const AIRDROP_QUEUE_REMAINING: Item<u32> = Item::new("aqr");
+
+/// Admin set cap on the network fees paid by airdrops, unlimited when unset
+pub const MAX_AIRDROP_FEE_SPEND: Item<Uint128> = Item::new("mafs");
+
+/// Network fees paid by airdrops so far
+pub const AIRDROP_FEE_SPENT: Item<Uint128> = Item::new("afs");
+
+/// Random seed embedded in the metadata of each on-chain metadata token, by token id
+pub const TOKEN_SEEDS: Map<&str, HexBinary> = Map::new("tsd");
+
+/// Times of the public mints per address still inside the rate limit window
+pub const RECENT_MINTS: Map<&Addr, Vec<Timestamp>> = Map::new("rcm");
+
+/// Remaining allowlist allocation per address, only usable before the start time
+pub const ALLOWLIST: Map<&Addr, u32> = Map::new("al");
+
+/// Accumulated amount sent to the seller, per denom
+pub const TOTAL_SELLER_REVENUE: Map<&str, Uint128> = Map::new("tsr");
+
+/// Accumulated network fees paid on mints, per denom
+pub const TOTAL_NETWORK_FEES: Map<&str, Uint128> = Map::new("tnf");
+
 pub fn increment_token_index(store: &mut dyn Storage) -> StdResult<u64> {
     let val = TOKEN_INDEX.may_load(store)?.unwrap_or_default() + 1;
     TOKEN_INDEX.save(store, &val)?;
     Ok(val)
 }
+
+pub fn record_unique_minter(store: &mut dyn Storage, height: u64) -> StdResult<u32> {
+    let val = UNIQUE_MINTER_COUNT.may_load(store)?.unwrap_or_default() + 1;
+    UNIQUE_MINTER_COUNT.save(store, &val)?;
+    UNIQUE_MINTER_CHECKPOINTS.save(store, height, &val)?;
+    Ok(val)
+}
+
+pub fn record_revenue(
+    store: &mut dyn Storage,
+    denom: &str,
+    seller_amount: Uint128,
+    network_fee: Uint128,
+) -> StdResult<()> {
+    let revenue = TOTAL_SELLER_REVENUE
+        .may_load(store, denom)?
+        .unwrap_or_default();
+    TOTAL_SELLER_REVENUE.save(store, denom, &(revenue + seller_amount))?;
+    let fees = TOTAL_NETWORK_FEES
+        .may_load(store, denom)?
+        .unwrap_or_default();
+    TOTAL_NETWORK_FEES.save(store, denom, &(fees + network_fee))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Timestamp};
+    use open_edition_factory::types::{NftData, NftMetadataType};
+    use sg_metadata::Metadata;
+
+    use super::ConfigExtension;
+
+    fn metadata(name: &str) -> Metadata {
+        Metadata {
+            name: Some(name.to_string()),
+            ..Metadata::default()
+        }
+    }
+
+    fn config_extension(extension_pool: Vec<Metadata>) -> ConfigExtension {
+        ConfigExtension {
+            admin: Addr::unchecked("admin"),
+            payment_address: None,
+            nft_data: NftData {
+                nft_data_type: NftMetadataType::OnChainMetadata,
+                extension: Some(metadata("base")),
+                token_uri: None,
+            },
+            start_time: Timestamp::from_seconds(0),
+            end_time: None,
+            per_address_limit: 1,
+            num_tokens: None,
+            lifetime_per_address_cap: None,
+            payment_splits: None,
+            allowlist_mint_price: None,
+            max_airdrop_tokens: None,
+            referral_bps: 0,
+            purge_delay_secs: 0,
+            start_height: None,
+            extension_pool,
+            shuffle_seed: None,
+            whitelist: None,
+            allowed_holder_collections: None,
+            token_id_prefix: None,
+            proceeds_contract: None,
+            unlimited_addresses: None,
+            dev_fee_address_override: None,
+            per_tx_limit: None,
+            cw20_mint_price: None,
+            randomness_provider: None,
+            allow_airdrop_after_end: false,
+            free_mint: false,
+            reserved_tokens: 0,
+            rate_limit: None,
+        }
+    }
+
+    #[test]
+    fn check_token_extension_cycles_pool() {
+        let config = config_extension(vec![metadata("a"), metadata("b"), metadata("c")]);
+        let names: Vec<_> = (1..=5)
+            .map(|token_index| config.token_extension(token_index).unwrap().name.unwrap())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c", "a", "b"]);
+    }
+
+    #[test]
+    fn check_token_extension_shuffled_pool() {
+        let pool: Vec<_> = (0..10).map(|i| metadata(&i.to_string())).collect();
+        let mut config = config_extension(pool.clone());
+        config.shuffle_seed = Some(42);
+        let mut assigned: Vec<_> = (1..=10)
+            .map(|token_index| config.token_extension(token_index).unwrap())
+            .collect();
+
+        // Same seed gives the same order
+        assert_eq!(config.token_extension(3), Some(assigned[2].clone()));
+
+        // Every entry is assigned exactly once
+        assigned.sort_by_key(|ext| ext.name.clone().unwrap().parse::<u32>().unwrap());
+        assert_eq!(assigned, pool);
+    }
+
+    #[test]
+    fn check_token_extension_empty_pool() {
+        let config = config_extension(vec![]);
+        assert_eq!(config.token_extension(7), Some(metadata("base")));
+    }
+}
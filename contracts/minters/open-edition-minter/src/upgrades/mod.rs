@@ -0,0 +1 @@
+pub mod v3_6_0;
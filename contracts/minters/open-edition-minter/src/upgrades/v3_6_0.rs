@@ -0,0 +1,73 @@
+use crate::state::{Config, ConfigExtension, CONFIG};
+use crate::ContractError;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Env, Event, Response, Timestamp};
+use cw_storage_plus::Item;
+use open_edition_factory::types::NftData;
+use sg4::MinterConfig;
+
+/// Config extension as stored by 3.5.0 and earlier minters
+#[cw_serde]
+pub struct LegacyConfigExtension {
+    pub admin: Addr,
+    pub payment_address: Option<Addr>,
+    pub nft_data: NftData,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub per_address_limit: u32,
+    pub num_tokens: Option<u32>,
+}
+
+pub const LEGACY_CONFIG: Item<MinterConfig<LegacyConfigExtension>> = Item::new("config");
+
+pub fn upgrade(deps: DepsMut, _env: &Env, response: Response) -> Result<Response, ContractError> {
+    // Nothing to do if the config was already written with the current layout
+    if CONFIG.load(deps.storage).is_ok() {
+        return Ok(response);
+    }
+    let legacy = LEGACY_CONFIG.load(deps.storage)?;
+
+    // New fields get the values instantiate uses when they are left unset
+    let config = Config {
+        factory: legacy.factory,
+        collection_code_id: legacy.collection_code_id,
+        mint_price: legacy.mint_price,
+        extension: ConfigExtension {
+            admin: legacy.extension.admin,
+            payment_address: legacy.extension.payment_address,
+            nft_data: legacy.extension.nft_data,
+            start_time: legacy.extension.start_time,
+            end_time: legacy.extension.end_time,
+            per_address_limit: legacy.extension.per_address_limit,
+            num_tokens: legacy.extension.num_tokens,
+            lifetime_per_address_cap: None,
+            payment_splits: None,
+            allowlist_mint_price: None,
+            max_airdrop_tokens: None,
+            referral_bps: 0,
+            purge_delay_secs: 0,
+            start_height: None,
+            extension_pool: vec![],
+            shuffle_seed: None,
+            whitelist: None,
+            allowed_holder_collections: None,
+            token_id_prefix: None,
+            proceeds_contract: None,
+            unlimited_addresses: None,
+            dev_fee_address_override: None,
+            per_tx_limit: None,
+            cw20_mint_price: None,
+            randomness_provider: None,
+            allow_airdrop_after_end: false,
+            free_mint: false,
+            reserved_tokens: 0,
+            rate_limit: None,
+        },
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let event = Event::new("migrate-3.6.0").add_attribute("config-upgraded", "true");
+
+    Ok(response.add_event(event))
+}
@@ -1,8 +1,38 @@
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Api, Decimal, Uint128};
+
+use sg721::RoyaltyInfoResponse;
+use url::Url;
 
 use crate::ContractError;
 use crate::ContractError::CheckedMultiplyFractionError;
 
+/// Maximum collection name length, in bytes
+pub const MAX_COLLECTION_NAME_BYTES: usize = 256;
+
+/// Maximum token id prefix length, in bytes
+pub const MAX_TOKEN_ID_PREFIX_BYTES: usize = 32;
+
+/// Maximum collection description length, in bytes, as enforced by sg721
+pub const MAX_COLLECTION_DESCRIPTION_BYTES: usize = 512;
+
+/// Maximum royalty share, the cap sg721 applies to royalty updates
+pub const MAX_ROYALTY_SHARE_PCT: u64 = 10;
+
+/// Contract labels are limited to 128 bytes by wasmd
+const MAX_LABEL_BYTES: usize = 128;
+
+/// Maximum number of entries a single batch execute processes, keeps gas bounded
+pub const MAX_BATCH: u32 = 50;
+
+/// Gateways serving `https://<gateway>/ipfs/<CID>/...` or `https://<CID>.ipfs.<gateway>/...`
+const IPFS_GATEWAYS: [&str; 5] = [
+    "ipfs.io",
+    "nftstorage.link",
+    "cf-ipfs.com",
+    "dweb.link",
+    "gateway.pinata.cloud",
+];
+
 pub fn get_three_percent_of_tokens(num_tokens: u32) -> Result<Uint128, ContractError> {
     let three_percent = (Uint128::new(3), Uint128::new(100));
     let three_percent_tokens = Uint128::from(num_tokens)
@@ -27,3 +57,174 @@ pub fn check_dynamic_per_address_limit(
     let result = Uint128::from(per_address_limit) <= three_percent_tokens;
     Ok(result)
 }
+
+pub fn validate_payment_splits(
+    api: &dyn Api,
+    payment_splits: Vec<(String, Decimal)>,
+) -> Result<Vec<(Addr, Decimal)>, ContractError> {
+    let total_share = payment_splits
+        .iter()
+        .fold(Decimal::zero(), |total, (_, share)| total + share);
+    if payment_splits.is_empty() || total_share != Decimal::one() {
+        return Err(ContractError::InvalidPaymentSplits {});
+    }
+    payment_splits
+        .into_iter()
+        .map(|(addr, share)| Ok((api.addr_validate(&addr)?, share)))
+        .collect()
+}
+
+pub fn validate_collection_name(name: &str) -> Result<(), ContractError> {
+    if name.trim().len() > MAX_COLLECTION_NAME_BYTES {
+        return Err(ContractError::CollectionNameTooLong {});
+    }
+    Ok(())
+}
+
+pub fn validate_collection_description(description: &str) -> Result<(), ContractError> {
+    if description.len() > MAX_COLLECTION_DESCRIPTION_BYTES {
+        return Err(ContractError::DescriptionTooLong {});
+    }
+    Ok(())
+}
+
+// Checked before the sg721 instantiate so a bad royalty doesn't surface as a reply error
+pub fn validate_royalty_info(
+    royalty_info: Option<&RoyaltyInfoResponse>,
+) -> Result<(), ContractError> {
+    if let Some(royalty_info) = royalty_info {
+        if royalty_info.share > Decimal::percent(MAX_ROYALTY_SHARE_PCT) {
+            return Err(ContractError::InvalidRoyalties {});
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_token_id_prefix(prefix: &str) -> Result<(), ContractError> {
+    if prefix.is_empty()
+        || prefix.len() > MAX_TOKEN_ID_PREFIX_BYTES
+        || !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(ContractError::InvalidTokenIdPrefix {});
+    }
+    Ok(())
+}
+
+// Build the sg721 label, truncated on a char boundary so multi-byte names fit the label limit
+pub fn sg721_label(name: &str) -> String {
+    let mut label = format!("SG721-{}", name.trim());
+    if label.len() > MAX_LABEL_BYTES {
+        let end = (0..=MAX_LABEL_BYTES)
+            .rev()
+            .find(|i| label.is_char_boundary(*i))
+            .unwrap_or_default();
+        label.truncate(end);
+    }
+    label
+}
+
+// Rewrite known IPFS gateway URLs into their canonical ipfs:// form, other URLs are kept as is
+pub fn normalize_ipfs_uri(uri: &str) -> Result<String, ContractError> {
+    let uri = uri.trim();
+    let url = Url::parse(uri).map_err(|_| ContractError::InvalidBaseTokenURI {})?;
+    if url.scheme() != "https" && url.scheme() != "http" {
+        return Ok(uri.to_string());
+    }
+    let host = url.host_str().unwrap_or_default();
+
+    // Path gateway: https://ipfs.io/ipfs/<CID>/1.json
+    if IPFS_GATEWAYS.contains(&host) {
+        if let Some(path) = url.path().strip_prefix("/ipfs/") {
+            if path.is_empty() {
+                return Err(ContractError::InvalidBaseTokenURI {});
+            }
+            return Ok(format!("ipfs://{path}"));
+        }
+        return Ok(uri.to_string());
+    }
+
+    // Subdomain gateway: https://<CID>.ipfs.nftstorage.link/1.json
+    if let Some((cid, gateway)) = host.split_once(".ipfs.") {
+        if IPFS_GATEWAYS.contains(&gateway) {
+            let path = url.path().trim_start_matches('/');
+            if path.is_empty() {
+                return Ok(format!("ipfs://{cid}"));
+            }
+            return Ok(format!("ipfs://{cid}/{path}"));
+        }
+    }
+
+    Ok(uri.to_string())
+}
+
+/// Shared limit of every batch entry point, on top of any stricter factory limit
+pub fn ensure_batch_len(len: usize) -> Result<(), ContractError> {
+    if len > MAX_BATCH as usize {
+        return Err(ContractError::BatchTooLarge { max: MAX_BATCH });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_ipfs_uri, sg721_label, validate_collection_name};
+    use crate::ContractError;
+
+    const CID: &str = "bafybeiavall5udkxkdtdm4djezoxrmfc6o5fn2ug3ymrlvibvwmwydgrkm";
+
+    #[test]
+    fn check_ipfs_uri_untouched() {
+        let uri = format!("ipfs://{CID}/1.jpg");
+        assert_eq!(normalize_ipfs_uri(&uri).unwrap(), uri);
+        assert_eq!(
+            normalize_ipfs_uri("https://example.com/1.json").unwrap(),
+            "https://example.com/1.json"
+        );
+    }
+
+    #[test]
+    fn check_ipfs_gateway_uris() {
+        let expected = format!("ipfs://{CID}/1.jpg");
+        let gateway_uris = [
+            format!("https://ipfs.io/ipfs/{CID}/1.jpg"),
+            format!("https://nftstorage.link/ipfs/{CID}/1.jpg"),
+            format!("https://{CID}.ipfs.nftstorage.link/1.jpg"),
+            format!("https://cf-ipfs.com/ipfs/{CID}/1.jpg"),
+            format!(" https://{CID}.ipfs.cf-ipfs.com/1.jpg "),
+        ];
+        for uri in gateway_uris {
+            assert_eq!(normalize_ipfs_uri(&uri).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn check_invalid_uris() {
+        assert_eq!(
+            normalize_ipfs_uri("not a url").unwrap_err(),
+            ContractError::InvalidBaseTokenURI {}
+        );
+        assert_eq!(
+            normalize_ipfs_uri("https://ipfs.io/ipfs/").unwrap_err(),
+            ContractError::InvalidBaseTokenURI {}
+        );
+    }
+
+    #[test]
+    fn check_emoji_collection_names() {
+        // Each emoji is 4 bytes, the label has to be cut without splitting one
+        let name = "\u{1F680}".repeat(40);
+        assert!(validate_collection_name(&name).is_ok());
+        let label = sg721_label(&name);
+        assert_eq!(label, format!("SG721-{}", "\u{1F680}".repeat(30)));
+        assert!(label.len() <= 128);
+
+        assert_eq!(sg721_label(" Stars \u{2728} "), "SG721-Stars \u{2728}");
+
+        assert_eq!(
+            validate_collection_name(&"\u{1F680}".repeat(65)).unwrap_err(),
+            ContractError::CollectionNameTooLong {}
+        );
+    }
+}
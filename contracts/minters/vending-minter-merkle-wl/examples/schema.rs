@@ -6,7 +6,7 @@ use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 use sg4::StatusResponse;
 use vending_minter_merkle_wl::msg::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, MintCountResponse, MintPriceResponse,
-    MintableNumTokensResponse, QueryMsg, StartTimeResponse,
+    MintableNumTokensResponse, QueryMsg, StartTimeResponse, VerifyAllowlistProofResponse,
 };
 use vending_minter_merkle_wl::state::Config;
 
@@ -26,4 +26,5 @@ fn main() {
     export_schema(&schema_for!(StartTimeResponse), &out_dir);
     export_schema(&schema_for!(MintPriceResponse), &out_dir);
     export_schema(&schema_for!(StatusResponse), &out_dir);
+    export_schema(&schema_for!(VerifyAllowlistProofResponse), &out_dir);
 }
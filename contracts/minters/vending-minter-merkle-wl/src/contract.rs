@@ -1,7 +1,7 @@
 use crate::error::ContractError;
 use crate::msg::{
     ConfigResponse, ExecuteMsg, MintCountResponse, MintPriceResponse, MintableNumTokensResponse,
-    QueryMsg, StartTimeResponse,
+    QueryMsg, StartTimeResponse, VerifyAllowlistProofResponse,
 };
 use crate::state::{
     Config, ConfigExtension, CONFIG, MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_POSITIONS, MINTER_ADDRS,
@@ -1143,6 +1143,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::MintableNumTokens {} => to_json_binary(&query_mintable_num_tokens(deps)?),
         QueryMsg::MintPrice {} => to_json_binary(&query_mint_price(deps)?),
         QueryMsg::MintCount { address } => to_json_binary(&query_mint_count(deps, address)?),
+        QueryMsg::VerifyAllowlistProof { address, proof } => {
+            to_json_binary(&query_verify_allowlist_proof(deps, address, proof)?)
+        }
     }
 }
 
@@ -1180,6 +1183,42 @@ fn query_mint_count(deps: Deps, address: String) -> StdResult<MintCountResponse>
     })
 }
 
+fn query_verify_allowlist_proof(
+    deps: Deps,
+    address: String,
+    proof: Vec<String>,
+) -> StdResult<VerifyAllowlistProofResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let no_merkle_root = || StdError::generic_err("No merkle root configured");
+
+    let whitelist = config.extension.whitelist.ok_or_else(no_merkle_root)?;
+    let wl_config: WhitelistConfigResponse = deps
+        .querier
+        .query_wasm_smart(whitelist.clone(), &WhitelistQueryMsg::Config {})?;
+    if !is_merkle_tree_wl(&wl_config) {
+        return Err(no_merkle_root());
+    }
+
+    let member = deps.api.addr_validate(&address)?;
+    // A malformed proof makes the whitelist error, it just doesn't prove membership
+    let valid = deps
+        .querier
+        .query_wasm_smart::<HasMemberResponse>(
+            whitelist,
+            &WhitelistMtreeQueryMsg::HasMember {
+                member: member.to_string(),
+                proof_hashes: proof,
+            },
+        )
+        .map(|res| res.has_member)
+        .unwrap_or(false);
+
+    Ok(VerifyAllowlistProofResponse {
+        valid,
+        price: wl_config.mint_price,
+    })
+}
+
 fn query_start_time(deps: Deps) -> StdResult<StartTimeResponse> {
     let config = CONFIG.load(deps.storage)?;
     Ok(StartTimeResponse {
@@ -47,8 +47,15 @@ pub enum QueryMsg {
     MintableNumTokens {},
     StartTime {},
     MintPrice {},
-    MintCount { address: String },
+    MintCount {
+        address: String,
+    },
     Status {},
+    /// Checks a merkle proof against the whitelist without minting
+    VerifyAllowlistProof {
+        address: String,
+        proof: Vec<String>,
+    },
 }
 
 #[cw_serde]
@@ -90,3 +97,9 @@ pub struct MintCountResponse {
     pub address: String,
     pub count: u32,
 }
+
+#[cw_serde]
+pub struct VerifyAllowlistProofResponse {
+    pub valid: bool,
+    pub price: Coin,
+}
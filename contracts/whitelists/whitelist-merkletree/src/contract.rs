@@ -2,7 +2,7 @@ use crate::admin::{
     can_execute, execute_freeze, execute_update_admins, query_admin_list, query_can_execute,
 };
 use crate::error::ContractError;
-use crate::helpers::crypto::{string_to_byte_slice, valid_hash_string, verify_merkle_root};
+use crate::helpers::crypto::{string_to_byte_slice, validate_proof_hashes, verify_merkle_root};
 use crate::helpers::utils::verify_tree_uri;
 use crate::helpers::validators::map_validate;
 use crate::msg::{
@@ -248,6 +248,7 @@ pub fn query_has_member(
     proof_hashes: Vec<String>,
 ) -> StdResult<HasMemberResponse> {
     deps.api.addr_validate(&member)?;
+    validate_proof_hashes(&proof_hashes).map_err(|e| StdError::generic_err(e.to_string()))?;
 
     let merkle_root = MERKLE_ROOT.load(deps.storage)?;
 
@@ -256,8 +257,6 @@ pub fn query_has_member(
     let final_hash = proof_hashes.into_iter().try_fold(
         member_init_hash_slice,
         |accum_hash_slice, new_proof_hashstring| {
-            valid_hash_string(&new_proof_hashstring)?;
-
             let mut hashe_slices = [
                 accum_hash_slice,
                 string_to_byte_slice(&new_proof_hashstring)?,
@@ -268,9 +267,9 @@ pub fn query_has_member(
     );
 
     if final_hash.is_err() {
-        return Err(cosmwasm_std::StdError::GenericErr {
-            msg: "Invalid Merkle Proof".to_string(),
-        });
+        return Err(StdError::generic_err(
+            ContractError::InvalidMerkleProof {}.to_string(),
+        ));
     }
 
     Ok(HasMemberResponse {
@@ -52,4 +52,10 @@ pub enum ContractError {
 
     #[error("InvalidHashString: {0}")]
     InvalidHashString(String),
+
+    #[error("Invalid Merkle Proof")]
+    InvalidMerkleProof {},
+
+    #[error("Merkle proof exceeds the maximum of 32 hashes")]
+    ProofTooLong {},
 }
@@ -1,5 +1,10 @@
 use cosmwasm_std::{HexBinary, StdError, StdResult};
 
+use crate::error::ContractError;
+
+// Deep enough for trees of 2^32 members, anything longer is only wasted hashing
+pub const MAX_PROOF_HASHES: usize = 32;
+
 pub fn valid_hash_string(hash_string: &String) -> StdResult<()> {
     let hex_res = HexBinary::from_hex(hash_string.as_str());
     if hex_res.is_err() {
@@ -21,6 +26,17 @@ pub fn valid_hash_string(hash_string: &String) -> StdResult<()> {
     Ok(())
 }
 
+// Checks the proof shape before any hashing is done
+pub fn validate_proof_hashes(proof_hashes: &[String]) -> Result<(), ContractError> {
+    if proof_hashes.len() > MAX_PROOF_HASHES {
+        return Err(ContractError::ProofTooLong {});
+    }
+    for proof_hash in proof_hashes {
+        valid_hash_string(proof_hash).map_err(|_| ContractError::InvalidMerkleProof {})?;
+    }
+    Ok(())
+}
+
 pub fn verify_merkle_root(merkle_root: &String) -> StdResult<()> {
     valid_hash_string(merkle_root)
 }
@@ -2,6 +2,7 @@
 mod tests {
     use crate::{
         contract::{execute, instantiate, query_config, query_has_member},
+        helpers::crypto::MAX_PROOF_HASHES,
         msg::{ExecuteMsg, InstantiateMsg},
         tests::test_helpers::get_merkle_tree_simple,
     };
@@ -226,4 +227,32 @@ mod tests {
         let proof = vec!["x".to_string(), "x".to_string()];
         let _ = query_has_member(deps.as_ref(), user.sender.to_string(), proof).unwrap_err();
     }
+
+    #[test]
+    fn query_membership_rejects_bad_proofs() {
+        let mut deps = mock_dependencies();
+
+        let tree = get_merkle_tree_simple(None);
+        setup_contract(deps.as_mut(), tree.root_hex());
+        let user = mock_info("stars1ye63jpm474yfrq02nyplrspyw75y82tptsls9t", &[]);
+
+        // too long, rejected before hashing
+        let proof = vec![
+            "ea930af5025204fc0dda1b69b567b6b41766107d65d46a1acd9725af65604531"
+                .to_string();
+            MAX_PROOF_HASHES + 1
+        ];
+        let err = query_has_member(deps.as_ref(), user.sender.to_string(), proof).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Merkle proof exceeds the maximum of 32 hashes"));
+
+        // malformed element, not 32 bytes of hex
+        let proof = vec![
+            "ea930af5025204fc0dda1b69b567b6b41766107d65d46a1acd9725af65604531".to_string(),
+            "zz930af5025204fc0dda1b69b567b6b41766107d65d46a1acd9725af65604531".to_string(),
+        ];
+        let err = query_has_member(deps.as_ref(), user.sender.to_string(), proof).unwrap_err();
+        assert!(err.to_string().contains("Invalid Merkle Proof"));
+    }
 }
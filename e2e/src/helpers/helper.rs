@@ -106,6 +106,8 @@ pub fn create_minter_msg(
                 external_link: None,
                 explicit_content: None,
                 royalty_info: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         },
     }
@@ -64,6 +64,11 @@ pub fn instantiate_factory(
                         denom: denom.to_string(),
                     },
                     dev_fee_address: dev_addr,
+                    max_batch_mint_size: 50,
+                    allowed_denoms: vec![],
+                    platform_fee_bps: None,
+                    platform_fee_address: None,
+                    verified_mint_fee_bps: None,
                 },
             },
         },
@@ -98,6 +103,27 @@ pub fn create_minter_msg(
             },
             per_address_limit: limit,
             end_time,
+            lifetime_per_address_cap: None,
+            payment_splits: None,
+            allowlist_mint_price: None,
+            max_airdrop_tokens: None,
+            referral_bps: None,
+            purge_delay_secs: None,
+            start_height: None,
+            extension_pool: None,
+            shuffle_seed: None,
+            allowed_holder_collections: None,
+            token_id_prefix: None,
+            proceeds_contract: None,
+            dev_fee_address_override: None,
+            per_tx_limit: None,
+            cw20_mint_price: None,
+            randomness_provider: None,
+            trading_start_base: None,
+            allow_airdrop_after_end: None,
+            free_mint: None,
+            reserved_tokens: None,
+            rate_limit: None,
         },
         collection_params: CollectionParams {
             code_id: code_id.unwrap_or_else(|| chain.orc.contract_map.code_id(SG721_NAME).unwrap()),
@@ -111,6 +137,8 @@ pub fn create_minter_msg(
                 external_link: None,
                 explicit_content: None,
                 royalty_info: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         },
     }
@@ -28,6 +28,8 @@ fn test_unauthorized_sg721_instantiation(chain: &mut Chain) {
                 royalty_info: None,
                 explicit_content: None,
                 start_trading_time: None,
+                max_supply: None,
+                enforce_royalties_on_transfer: None,
             },
         },
         &user.key,
@@ -19,6 +19,8 @@ pub fn mock_collection_params() -> CollectionParams {
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(10),
             }),
+            max_supply: None,
+            enforce_royalties_on_transfer: None,
         },
     }
 }
@@ -39,6 +41,8 @@ pub fn mock_collection_params_1(start_trading_time: Option<Timestamp>) -> Collec
                 payment_address: "creator".to_string(),
                 share: Decimal::percent(10),
             }),
+            max_supply: None,
+            enforce_royalties_on_transfer: None,
         },
     }
 }
@@ -59,6 +63,8 @@ pub fn mock_curator_payment_address(start_trading_time: Option<Timestamp>) -> Co
             }),
             start_trading_time,
             explicit_content: None,
+            max_supply: None,
+            enforce_royalties_on_transfer: None,
         },
     }
 }
@@ -81,6 +87,8 @@ pub fn mock_collection_params_high_fee(start_trading_time: Option<Timestamp>) ->
             }),
             start_trading_time,
             explicit_content: None,
+            max_supply: None,
+            enforce_royalties_on_transfer: None,
         },
     }
 }
@@ -103,6 +111,8 @@ pub fn mock_collection_two(start_trading_time: Option<Timestamp>) -> CollectionP
             }),
             start_trading_time,
             explicit_content: None,
+            max_supply: None,
+            enforce_royalties_on_transfer: None,
         },
     }
 }
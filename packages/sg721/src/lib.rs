@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Timestamp};
 use cw_ownable::cw_ownable_execute;
 use cw_utils::Expiration;
 
@@ -10,6 +10,10 @@ pub enum ExecuteMsg<T, E> {
     TransferNft {
         recipient: String,
         token_id: String,
+        /// Sale price declared by the sender, required when the collection enforces royalties on
+        /// transfer. Informational only: it is not verified, so it can be understated. Can be
+        /// left out when the collection does not enforce royalties
+        sale_price: Option<Coin>,
     },
     /// Send is a base message to transfer a token to a contract and trigger an action
     /// on the receiving contract.
@@ -17,6 +21,10 @@ pub enum ExecuteMsg<T, E> {
         contract: String,
         token_id: String,
         msg: Binary,
+        /// Sale price declared by the sender, required when the collection enforces royalties on
+        /// transfer. Informational only: it is not verified, so it can be understated. Can be
+        /// left out when the collection does not enforce royalties
+        sale_price: Option<Coin>,
     },
     /// Allows operator to transfer / send the token from the owner's account.
     /// If expiration is set, then this allowance has a time/height limit
@@ -80,6 +88,12 @@ pub struct CollectionInfo<T> {
     pub explicit_content: Option<bool>,
     pub start_trading_time: Option<Timestamp>,
     pub royalty_info: Option<T>,
+    /// Collection-wide supply cap enforced on mint, independently from the minter
+    pub max_supply: Option<u32>,
+    /// Transfers must pay the royalty share of the non zero sale price they declare, off by
+    /// default. Advisory only: the price is self-declared, so this relies on marketplaces
+    /// declaring the real one
+    pub enforce_royalties_on_transfer: Option<bool>,
 }
 
 #[cw_serde]
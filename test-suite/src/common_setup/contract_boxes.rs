@@ -41,7 +41,8 @@ pub fn contract_open_edition_factory() -> Box<dyn Contract<Empty>> {
         open_edition_factory::contract::instantiate,
         open_edition_factory::contract::query,
     )
-    .with_sudo(open_edition_factory::contract::sudo);
+    .with_sudo(open_edition_factory::contract::sudo)
+    .with_reply(open_edition_factory::contract::reply);
     Box::new(contract)
 }
 
@@ -89,7 +90,9 @@ pub fn contract_open_edition_minter() -> Box<dyn Contract<Empty>> {
         open_edition_minter::contract::instantiate,
         open_edition_minter::contract::query,
     )
-    .with_reply(open_edition_minter::contract::reply);
+    .with_reply(open_edition_minter::contract::reply)
+    .with_sudo(open_edition_minter::contract::sudo)
+    .with_migrate(open_edition_minter::contract::migrate);
     Box::new(contract)
 }
 
@@ -103,6 +106,16 @@ pub fn contract_vending_minter() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn contract_vending_minter_merkle_wl() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        vending_minter_merkle_wl::contract::execute,
+        vending_minter_merkle_wl::contract::instantiate,
+        vending_minter_merkle_wl::contract::query,
+    )
+    .with_reply(vending_minter_merkle_wl::contract::reply);
+    Box::new(contract)
+}
+
 pub fn contract_sg721_base() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         sg721_base::entry::execute,
@@ -167,3 +180,12 @@ pub fn contract_whitelist_merkletree() -> Box<dyn Contract<Empty>> {
     );
     Box::new(contract)
 }
+
+pub fn contract_cw20() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new_with_empty(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
@@ -1,6 +1,6 @@
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Event, Uint128};
 use cw4::Member;
-use cw_multi_test::{App, AppBuilder};
+use cw_multi_test::{App, AppBuilder, AppResponse};
 
 const OWNER: &str = "admin0001";
 
@@ -19,3 +19,30 @@ pub fn member<T: Into<String>>(addr: T, weight: u64) -> Member {
         weight,
     }
 }
+
+/// Amounts moved by the fair burns of a response
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FairBurnAmounts {
+    pub burned: Uint128,
+    /// Share sent to the developer, 0 when the remainder funded the fairburn pool
+    pub dev: Uint128,
+}
+
+/// Sums every fair-burn event emitted while executing the response's messages
+pub fn fair_burn_amounts(res: &AppResponse) -> FairBurnAmounts {
+    let attribute_amount = |event: &Event, key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.parse::<Uint128>().unwrap())
+            .unwrap_or_default()
+    };
+    res.events
+        .iter()
+        .filter(|event| event.ty == "wasm-fair-burn")
+        .fold(FairBurnAmounts::default(), |total, event| FairBurnAmounts {
+            burned: total.burned + attribute_amount(event, "burn_amount"),
+            dev: total.dev + attribute_amount(event, "dev_amount"),
+        })
+}
@@ -89,6 +89,10 @@ pub fn setup_block_time(router: &mut App, nanos: u64, height: Option<u64>) {
     router.set_block(block);
 }
 
+pub fn set_block_time(router: &mut App, time: Timestamp) {
+    router.update_block(|block| block.time = time);
+}
+
 // Deal with zero and non-zero coin amounts for msgs
 pub fn coins_for_msg(msg_coin: Coin) -> Vec<Coin> {
     if msg_coin.amount > Uint128::zero() {
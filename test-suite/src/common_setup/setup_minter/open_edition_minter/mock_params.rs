@@ -29,6 +29,27 @@ pub fn mock_init_minter_extension(
         end_time,
         payment_address,
         num_tokens,
+        lifetime_per_address_cap: None,
+        payment_splits: None,
+        allowlist_mint_price: None,
+        max_airdrop_tokens: None,
+        referral_bps: None,
+        purge_delay_secs: None,
+        start_height: None,
+        extension_pool: None,
+        shuffle_seed: None,
+        allowed_holder_collections: None,
+        token_id_prefix: None,
+        proceeds_contract: None,
+        dev_fee_address_override: None,
+        per_tx_limit: None,
+        cw20_mint_price: None,
+        randomness_provider: None,
+        trading_start_base: None,
+        allow_airdrop_after_end: None,
+        free_mint: None,
+        reserved_tokens: None,
+        rate_limit: None,
     }
 }
 
@@ -85,6 +106,11 @@ pub fn mock_params_proper() -> OpenEditionMinterParams {
                 amount: Uint128::new(100_000_000u128),
             },
             dev_fee_address: DEV_ADDRESS.to_string(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     }
 }
@@ -112,6 +138,11 @@ pub fn mock_params_custom(custom_params: OpenEditionMinterCustomParams) -> OpenE
                 amount: airdrop_mint_price_amount,
             },
             dev_fee_address: DEV_ADDRESS.to_string(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     }
 }
@@ -134,6 +165,11 @@ pub fn mock_params_custom_min_mint_price(
             airdrop_mint_fee_bps: 100,
             airdrop_mint_price,
             dev_fee_address: DEV_ADDRESS.to_string(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     }
 }
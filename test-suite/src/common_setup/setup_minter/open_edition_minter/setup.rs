@@ -13,7 +13,9 @@ use open_edition_factory::msg::{
     OpenEditionMinterInitMsgExtension, OpenEditionUpdateParamsExtension, OpenEditionUpdateParamsMsg,
 };
 use open_edition_factory::types::NftData;
+use open_edition_minter::msg::ExecuteMsg;
 use sg2::msg::{CollectionParams, Sg2ExecuteMsg};
+use sg2::tests::mock_collection_params_1;
 
 use sg_std::NATIVE_DENOM;
 
@@ -123,6 +125,60 @@ pub fn setup_open_edition_minter_contract(
     }
 }
 
+// Store the code ids, instantiate the factory with the default params and create a minter
+pub fn setup_open_edition_minter(
+    router: &mut App,
+    minter_admin: Addr,
+    init_msg: OpenEditionMinterInitMsgExtension,
+) -> MinterCollectionResponse {
+    let code_ids = open_edition_minter_code_ids(router);
+    let setup_params = OpenEditionMinterSetupParams {
+        router,
+        minter_admin,
+        collection_params: mock_collection_params_1(None),
+        start_time: Some(init_msg.start_time),
+        nft_data: init_msg.nft_data.clone(),
+        per_address_limit: init_msg.per_address_limit,
+        end_time: init_msg.end_time,
+        num_tokens: init_msg.num_tokens,
+        minter_code_id: code_ids.minter_code_id,
+        factory_code_id: code_ids.factory_code_id,
+        sg721_code_id: code_ids.sg721_code_id,
+        init_msg: Some(init_msg),
+        custom_params: None,
+    };
+    setup_open_edition_minter_contract(setup_params)
+}
+
+// Mint n tokens from the buyer at the given price, returns the minted token ids
+pub fn mint_n_tokens(
+    router: &mut App,
+    minter: &Addr,
+    buyer: &Addr,
+    n: u32,
+    price: Coin,
+) -> Vec<String> {
+    let mut token_ids = vec![];
+    for _ in 0..n {
+        let res = router
+            .execute_contract(
+                buyer.clone(),
+                minter.clone(),
+                &ExecuteMsg::Mint {},
+                &[price.clone()],
+            )
+            .unwrap();
+        token_ids.extend(
+            res.events
+                .iter()
+                .flat_map(|event| event.attributes.iter())
+                .filter(|attr| attr.key == "token_id")
+                .map(|attr| attr.value.clone()),
+        );
+    }
+    token_ids
+}
+
 pub fn open_edition_minter_code_ids(router: &mut App) -> CodeIds {
     let minter_code_id = router.store_code(contract_open_edition_minter());
 
@@ -166,6 +222,11 @@ pub fn sudo_update_params(
                     max_token_limit: None,
                     airdrop_mint_price: None,
                     airdrop_mint_fee_bps: None,
+                    max_batch_mint_size: None,
+                    allowed_denoms: None,
+                    verified_mint_fee_bps: None,
+                    platform_fee_bps: None,
+                    platform_fee_address: None,
                 },
             },
         };
@@ -19,6 +19,8 @@ mod vending_factory;
 #[cfg(test)]
 mod vending_minter;
 #[cfg(test)]
+mod vending_minter_merkle_wl;
+#[cfg(test)]
 mod whitelist;
 #[cfg(test)]
 mod whitelist_immutable;
@@ -44,6 +44,11 @@ mod tests {
                     airdrop_mint_fee_bps: None,
                     airdrop_mint_price: None,
                     dev_fee_address: None,
+                    max_batch_mint_size: None,
+                    allowed_denoms: None,
+                    verified_mint_fee_bps: None,
+                    platform_fee_bps: None,
+                    platform_fee_address: None,
                 },
             };
             let sudo_msg = SudoMsg::UpdateParams(Box::new(update_msg));
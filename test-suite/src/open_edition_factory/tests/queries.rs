@@ -1,11 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{from_json, Addr};
     use cw_multi_test::Executor;
 
     use open_edition_factory::helpers::FactoryContract;
     use open_edition_factory::msg::InstantiateMsg;
-    use open_edition_factory::state::OpenEditionMinterParams;
+    use open_edition_factory::state::{
+        OpenEditionMinterParams, ParamsExtension, DEFAULT_MAX_BATCH_MINT_SIZE,
+    };
 
     use crate::common_setup::contract_boxes::{
         contract_open_edition_factory, custom_mock_app, App,
@@ -119,5 +121,22 @@ mod tests {
                 .unwrap();
             assert!(!res.allowed);
         }
+
+        #[test]
+        fn params_stored_before_batch_mints_test() {
+            // Params of a factory deployed before the batch mint size and allowed denoms were added
+            let extension: ParamsExtension = from_json(
+                r#"{
+                    "max_token_limit": 10,
+                    "max_per_address_limit": 10,
+                    "airdrop_mint_fee_bps": 100,
+                    "airdrop_mint_price": { "denom": "ustars", "amount": "100000000" },
+                    "dev_fee_address": "dev"
+                }"#,
+            )
+            .unwrap();
+            assert_eq!(extension.max_batch_mint_size, DEFAULT_MAX_BATCH_MINT_SIZE);
+            assert!(extension.allowed_denoms.is_empty());
+        }
     }
 }
@@ -6,11 +6,16 @@ use crate::common_setup::setup_minter::open_edition_minter::setup::sudo_update_p
 use crate::common_setup::templates::open_edition_minter_custom_template;
 use base_factory::msg::ParamsResponse;
 use cosmwasm_std::{coin, Coin, Timestamp, Uint128};
-use open_edition_factory::msg::OpenEditionUpdateParamsExtension;
+use open_edition_factory::msg::{
+    OpenEditionUpdateParamsExtension, ParamsResponse as OpenEditionParamsResponse,
+};
 use open_edition_factory::state::ParamsExtension;
+use open_edition_minter::msg::{ConfigResponse, QueryMsg};
 use sg2::query::Sg2QueryMsg::Params;
 use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
+const IBC_DENOM: &str = "ibc/frenz";
+
 #[test]
 fn happy_path_with_params_update() {
     let params_extension = ParamsExtension {
@@ -22,6 +27,11 @@ fn happy_path_with_params_update() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg = init_msg(
@@ -48,6 +58,11 @@ fn sudo_params_update_creation_fee() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg = init_msg(
@@ -79,6 +94,11 @@ fn sudo_params_update_creation_fee() {
             airdrop_mint_price: None,
             airdrop_mint_fee_bps: None,
             dev_fee_address: Some(DEV_ADDRESS.to_string()),
+            max_batch_mint_size: None,
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
         },
     };
     sudo_update_params(
@@ -91,3 +111,147 @@ fn sudo_params_update_creation_fee() {
     let res: ParamsResponse = router.wrap().query_wasm_smart(factory, &Params {}).unwrap();
     assert_eq!(res.params.creation_fee, coin(999, NATIVE_DENOM));
 }
+
+#[test]
+fn sudo_params_update_allowed_denoms() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec!["uatom".to_string()],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+
+    // Mint price denom is not in the list
+    let vt =
+        open_edition_minter_custom_template(params_extension.clone(), init_msg.clone()).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        format!("DenomNotAllowed: {NATIVE_DENOM}")
+    );
+
+    let params_extension = ParamsExtension {
+        allowed_denoms: vec![NATIVE_DENOM.to_string()],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+        ..params_extension
+    };
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    assert!(vt.collection_response_vec[0].error.is_none());
+    let factory = vt.collection_response_vec[0].factory.clone().unwrap();
+    let mut router = vt.router;
+
+    let update_msg = sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            min_mint_price: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: Some(vec![NATIVE_DENOM.to_string(), "uatom".to_string()]),
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
+        },
+    };
+    sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(update_msg),
+    );
+
+    let res: OpenEditionParamsResponse =
+        router.wrap().query_wasm_smart(factory, &Params {}).unwrap();
+    assert_eq!(
+        res.params.extension.allowed_denoms,
+        vec![NATIVE_DENOM.to_string(), "uatom".to_string()]
+    );
+}
+
+#[test]
+fn create_minter_in_allowed_denom() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    // Priced below the native min mint price, which doesn't apply to other denoms
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(1_000_000, IBC_DENOM)),
+    );
+
+    // Without the allowlist only the min mint price denom is accepted
+    let vt =
+        open_edition_minter_custom_template(params_extension.clone(), init_msg.clone()).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        "InvalidDenom"
+    );
+
+    let params_extension = ParamsExtension {
+        allowed_denoms: vec![NATIVE_DENOM.to_string(), IBC_DENOM.to_string()],
+        ..params_extension
+    };
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    assert!(vt.collection_response_vec[0].error.is_none());
+    let minter = vt.collection_response_vec[0].minter.clone().unwrap();
+    let res: ConfigResponse = vt
+        .router
+        .wrap()
+        .query_wasm_smart(minter, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.mint_price, coin(1_000_000, IBC_DENOM));
+}
@@ -1,9 +1,75 @@
 mod address_limit;
+mod airdrop_after_end;
+mod airdrop_fee;
+mod airdrop_fee_cap;
+mod airdrop_limit;
+mod airdrop_queue;
+mod allowlist;
 mod allowed_code_ids;
+mod batch_limits;
+mod can_mint;
+mod cancel_mint;
+mod collection_event;
+mod collection_max_supply;
+mod collection_query;
 mod complete_mint_all_outcomes_validation;
+mod contract_info;
+mod current_token_index;
+mod cw20_payment;
+mod dev_fee_override;
+mod display_price;
+mod extension_pool;
 mod factory_create_minter;
+mod factory_minters;
+mod factory_params;
+mod fair_burn;
+mod fee_schedule;
+mod force_end_mint;
+mod free_mint;
+mod freeze_config;
 mod frozen_factory;
+mod full_status;
 mod ibc_asset_mint;
+mod instantiate_validation;
+mod lifetime_limit;
 mod max_tokens_limit;
+mod metadata_migration;
+mod migrate_collection;
+mod mint_as_holder;
+mod mint_batch;
+mod mint_counts;
+mod mint_for;
+mod mint_to_batch;
+mod next_token_metadata;
+mod override_per_address_limit;
+mod payment_splits;
+mod per_tx_limit;
+mod proceeds_contract;
+mod platform_fee;
+mod purge;
+mod purge_delay;
+mod quote_batch;
+mod rate_limit;
+mod referral;
+mod reopen_mint;
+mod reserved_tokens;
+mod resolved_config;
+mod revenue;
+mod set_sg721_address;
+mod shuffle_seed;
+mod simulate_mint;
+mod start_height;
+mod state_migration;
+mod status_update;
+mod time_remaining;
+mod token_id_prefix;
+mod token_seed;
+mod trading_start_base;
+mod unique_minter_curve;
+mod unlimited_addresses;
+mod update_collection_info;
 mod update_mint_price;
 mod update_start_and_end_time;
+mod update_start_trading_time;
+mod verified_mint_fee;
+mod whitelist_mint;
@@ -0,0 +1,147 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const AIRDROP_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_mint_to_after_end_time() {
+    for allow_airdrop_after_end in [None, Some(false), Some(true)] {
+        let mut init_msg = init_msg(
+            default_nft_data(),
+            None,
+            None,
+            Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+            None,
+            None,
+        );
+        init_msg.allow_airdrop_after_end = allow_airdrop_after_end;
+        let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+        let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+        let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+        let allowed = allow_airdrop_after_end.unwrap_or_default();
+
+        let res: ConfigResponse = router
+            .wrap()
+            .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(res.allow_airdrop_after_end, allowed);
+
+        // Past the end time
+        setup_block_time(&mut router, GENESIS_MINT_START_TIME + 20_000, None);
+
+        let res = router.execute_contract(
+            creator,
+            minter_addr.clone(),
+            &ExecuteMsg::MintTo {
+                recipient: buyer.to_string(),
+            },
+            &coins(AIRDROP_PRICE, NATIVE_DENOM),
+        );
+        if allowed {
+            assert!(res.is_ok());
+        } else {
+            assert_eq!(
+                res.err().unwrap().source().unwrap().to_string(),
+                "Minting has ended"
+            );
+        }
+
+        // Public mints close at the end time either way
+        let res = router.execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        );
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn check_batch_airdrops_after_end_time() {
+    for allowed in [false, true] {
+        let mut init_msg = init_msg(
+            default_nft_data(),
+            None,
+            None,
+            Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+            None,
+            None,
+        );
+        init_msg.allow_airdrop_after_end = Some(allowed);
+        let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+        let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+        let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+        // Past the end time
+        setup_block_time(&mut router, GENESIS_MINT_START_TIME + 20_000, None);
+
+        let batch_msgs = [
+            (
+                ExecuteMsg::MintToBatch {
+                    recipients: vec![buyer.to_string(), creator.to_string()],
+                },
+                2,
+            ),
+            (
+                ExecuteMsg::MintToWeighted {
+                    recipients: vec![(buyer.to_string(), 2), (creator.to_string(), 1)],
+                },
+                3,
+            ),
+            (
+                ExecuteMsg::QueueAirdrop {
+                    recipients: vec![buyer.to_string(), creator.to_string()],
+                },
+                2,
+            ),
+        ];
+        for (msg, count) in batch_msgs {
+            let res = router.execute_contract(
+                creator.clone(),
+                minter_addr.clone(),
+                &msg,
+                &coins(AIRDROP_PRICE * count, NATIVE_DENOM),
+            );
+            if allowed {
+                assert!(res.is_ok());
+            } else {
+                assert_eq!(
+                    res.err().unwrap().source().unwrap().to_string(),
+                    "Minting has ended"
+                );
+            }
+        }
+    }
+}
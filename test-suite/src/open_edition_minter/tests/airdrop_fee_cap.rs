@@ -0,0 +1,127 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, TotalMintCountResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const AIRDROP_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_airdrop_fee_cap() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Only the admin sets the cap
+    let update_cap = |max: Option<u128>| ExecuteMsg::UpdateMaxAirdropFeeSpend {
+        max_airdrop_fee_spend: max.map(Uint128::new),
+    };
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &update_cap(Some(0)),
+        &[],
+    );
+    assert!(res.is_err());
+
+    // Each airdrop pays a 1_000_000 network fee, the cap covers two of them
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &update_cap(Some(2_500_000)),
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let airdrop_batch = |count: usize| ExecuteMsg::MintToBatch {
+        recipients: vec![buyer.to_string(); count],
+    };
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &airdrop_batch(3),
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Airdrop would exceed the max airdrop fee spend"
+    );
+    // Nothing of the rejected batch was minted
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 0);
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &airdrop_batch(2),
+        &coins(AIRDROP_PRICE * 2, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // The spend is cumulative
+    let mint_to = ExecuteMsg::MintTo {
+        recipient: buyer.to_string(),
+    };
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &mint_to,
+        &coins(AIRDROP_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Airdrop would exceed the max airdrop fee spend"
+    );
+
+    // Removing the cap lifts the limit
+    let res = router.execute_contract(creator.clone(), minter_addr.clone(), &update_cap(None), &[]);
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &mint_to,
+        &coins(AIRDROP_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+}
@@ -0,0 +1,308 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{
+    AirdropQueueResponse, ExecuteMsg, QueryMsg, TotalMintCountResponse,
+};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const AIRDROP_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_airdrop_queue() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(3),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let recipients = vec![buyer.to_string(); 4];
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop {
+            recipients: recipients.clone(),
+        },
+        &coins(AIRDROP_PRICE * 4, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    // The airdrop price of every recipient is paid upfront
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop {
+            recipients: recipients.clone(),
+        },
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_err());
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop { recipients },
+        &coins(AIRDROP_PRICE * 4, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: AirdropQueueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::AirdropQueue {})
+        .unwrap();
+    assert_eq!(res.remaining, 4);
+
+    // Anyone can process the queue
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ProcessAirdrop { limit: 51 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Batch too large, max: 50"
+    );
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ProcessAirdrop { limit: 2 },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: AirdropQueueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::AirdropQueue {})
+        .unwrap();
+    assert_eq!(res.remaining, 2);
+
+    // Only one token left, the other entry is dropped and its escrow refunded
+    let creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ProcessAirdrop { limit: 10 },
+        &[],
+    );
+    assert!(res.is_ok());
+    let new_creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        new_creator_balance.amount,
+        creator_balance.amount + Uint128::new(AIRDROP_PRICE)
+    );
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+    let res: AirdropQueueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::AirdropQueue {})
+        .unwrap();
+    assert_eq!(res.remaining, 0);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::ProcessAirdrop { limit: 10 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Airdrop queue is empty"
+    );
+}
+
+#[test]
+fn check_dequeue_stuck_airdrop() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop {
+            recipients: vec![buyer.to_string(); 3],
+        },
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // The fee cap makes the head of the queue unmintable
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateMaxAirdropFeeSpend {
+            max_airdrop_fee_spend: Some(Uint128::one()),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ProcessAirdrop { limit: 1 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Airdrop would exceed the max airdrop fee spend"
+    );
+
+    // Only the admin can drop entries
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::DequeueAirdrop { limit: 2 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    let creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::DequeueAirdrop { limit: 2 },
+        &[],
+    );
+    assert!(res.is_ok());
+    let new_creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        new_creator_balance.amount,
+        creator_balance.amount + Uint128::new(AIRDROP_PRICE * 2)
+    );
+    let res: AirdropQueueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::AirdropQueue {})
+        .unwrap();
+    assert_eq!(res.remaining, 1);
+
+    // The rest of the queue goes through once the cap allows it
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateMaxAirdropFeeSpend {
+            max_airdrop_fee_spend: None,
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::ProcessAirdrop { limit: 1 },
+        &[],
+    );
+    assert!(res.is_ok());
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 1);
+}
+
+#[test]
+fn check_cancel_mint_clears_airdrop_queue() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop {
+            recipients: vec![buyer.to_string(); 2],
+        },
+        &coins(AIRDROP_PRICE * 2, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // The escrow is refunded with the balance and the queue emptied
+    let creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::CancelMint {},
+        &[],
+    );
+    assert!(res.is_ok());
+    let new_creator_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        new_creator_balance.amount,
+        creator_balance.amount + Uint128::new(AIRDROP_PRICE * 2)
+    );
+    let res: AirdropQueueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::AirdropQueue {})
+        .unwrap();
+    assert_eq!(res.remaining, 0);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::ProcessAirdrop { limit: 2 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Airdrop queue is empty"
+    );
+}
@@ -0,0 +1,121 @@
+use cosmwasm_std::{coin, coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{AllowlistAllocationResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 200_000_000;
+const ALLOWLIST_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_allowlist_mint() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 1_000);
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        Some(start_time),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(MINT_PRICE, NATIVE_DENOM)),
+    );
+    init_msg.allowlist_mint_price = Some(coin(ALLOWLIST_PRICE, NATIVE_DENOM));
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let set_allowlist_msg = ExecuteMsg::SetAllowlist {
+        entries: vec![(buyer.to_string(), 2)],
+    };
+    let res = router.execute_contract(buyer.clone(), minter_addr.clone(), &set_allowlist_msg, &[]);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &set_allowlist_msg,
+        &[],
+    );
+    assert!(res.is_ok());
+
+    // Allowlisted addresses mint at the allowlist price before the start time
+    for _ in 1..=2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::MintAllowlist {},
+            &coins(ALLOWLIST_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintAllowlist {},
+        &coins(ALLOWLIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Allowlist allocation exhausted"
+    );
+
+    let res: AllowlistAllocationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::AllowlistAllocation {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.allocation, 0);
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::MintAllowlist {},
+        &coins(ALLOWLIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "address not on whitelist: creator"
+    );
+
+    // Once the public mint started, the allowlist can't be used anymore
+    setup_block_time(&mut router, start_time.nanos(), None);
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::MintAllowlist {},
+        &coins(ALLOWLIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Allowlist minting is only possible before the start time"
+    );
+}
@@ -0,0 +1,92 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+use open_edition_minter::validation::MAX_BATCH;
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_batch_endpoints_reject_oversized_batches() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        // Above the shared limit, so the minter's own cap applies
+        max_batch_mint_size: 100,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let oversized = MAX_BATCH + 1;
+    let addresses = (0..oversized)
+        .map(|i| format!("recipient{i}"))
+        .collect::<Vec<_>>();
+    let expected_error = format!("Batch too large, max: {MAX_BATCH}");
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: oversized,
+            best_effort: None,
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        expected_error
+    );
+
+    let admin_msgs = vec![
+        ExecuteMsg::MintToBatch {
+            recipients: addresses.clone(),
+        },
+        ExecuteMsg::MintToWeighted {
+            recipients: addresses.iter().map(|addr| (addr.clone(), 1)).collect(),
+        },
+        // A single entry expanding past the limit is rejected as well
+        ExecuteMsg::MintToWeighted {
+            recipients: vec![(addresses[0].clone(), oversized)],
+        },
+        ExecuteMsg::SetAllowlist {
+            entries: addresses.iter().map(|addr| (addr.clone(), 1)).collect(),
+        },
+        ExecuteMsg::SetUnlimitedAddresses {
+            addresses: addresses.clone(),
+        },
+    ];
+    for msg in admin_msgs {
+        let res = router.execute_contract(creator.clone(), minter_addr.clone(), &msg, &[]);
+        assert_eq!(
+            res.err().unwrap().source().unwrap().to_string(),
+            expected_error
+        );
+    }
+}
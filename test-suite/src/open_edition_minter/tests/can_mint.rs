@@ -0,0 +1,174 @@
+use cosmwasm_std::{coins, Addr, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{CanMintResponse, ExecuteMsg, QueryMsg, SudoMsg};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const END_TIME: u64 = GENESIS_MINT_START_TIME + 10_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn can_mint(router: &App, minter: &Addr, address: &Addr) -> CanMintResponse {
+    router
+        .wrap()
+        .query_wasm_smart(
+            minter,
+            &QueryMsg::CanMint {
+                address: address.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+fn assert_rejected(res: CanMintResponse, reason: &str) {
+    assert_eq!(
+        res,
+        CanMintResponse {
+            can_mint: false,
+            reason: Some(reason.to_string()),
+        }
+    );
+}
+
+fn mint(router: &mut App, minter: &Addr, sender: &Addr) {
+    router
+        .execute_contract(
+            sender.clone(),
+            minter.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        )
+        .unwrap();
+}
+
+#[test]
+fn check_can_mint_window_and_limit() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(1),
+        None,
+        Some(Timestamp::from_nanos(END_TIME)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    assert_rejected(
+        can_mint(&router, &minter_addr, &buyer),
+        "Minting has not started yet",
+    );
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    assert_eq!(
+        can_mint(&router, &minter_addr, &buyer),
+        CanMintResponse {
+            can_mint: true,
+            reason: None,
+        }
+    );
+
+    // The answer matches the outcome of the mint
+    mint(&mut router, &minter_addr, &buyer);
+    assert_rejected(
+        can_mint(&router, &minter_addr, &buyer),
+        "Max minting limit per address exceeded",
+    );
+
+    setup_block_time(&mut router, END_TIME, None);
+    assert_rejected(can_mint(&router, &minter_addr, &buyer), "Minting has ended");
+}
+
+#[test]
+fn check_can_mint_blocked_and_cancelled() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(END_TIME)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::CancelMint {},
+        &[],
+    );
+    assert!(res.is_ok());
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    assert_rejected(
+        can_mint(&router, &minter_addr, &buyer),
+        "Mint was cancelled",
+    );
+
+    let res = router.wasm_sudo(
+        minter_addr.clone(),
+        &SudoMsg::UpdateStatus {
+            is_verified: false,
+            is_blocked: true,
+            is_explicit: false,
+        },
+    );
+    assert!(res.is_ok());
+    assert_rejected(can_mint(&router, &minter_addr, &buyer), "Minter is blocked");
+}
+
+#[test]
+fn check_can_mint_supply_and_lifetime_cap() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(3),
+        None,
+        Some(Timestamp::from_nanos(END_TIME)),
+        Some(2),
+        None,
+    );
+    init_msg.lifetime_per_address_cap = Some(1);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Under the per address limit but at the lifetime cap
+    mint(&mut router, &minter_addr, &buyer);
+    assert_rejected(
+        can_mint(&router, &minter_addr, &buyer),
+        "Lifetime minting limit per address exceeded",
+    );
+
+    mint(&mut router, &minter_addr, &creator);
+    assert_rejected(can_mint(&router, &minter_addr, &creator), "Sold out");
+}
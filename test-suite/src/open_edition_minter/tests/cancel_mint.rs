@@ -0,0 +1,161 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_cancel_mint_before_start() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // Only the admin can cancel
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::CancelMint {},
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    // Only the queued airdrop escrow goes back to the admin, unrelated funds are not swept
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::QueueAirdrop {
+            recipients: vec![buyer.to_string()],
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+    router
+        .send_tokens(
+            buyer.clone(),
+            minter_addr.clone(),
+            &coins(1_000_000, NATIVE_DENOM),
+        )
+        .unwrap();
+    let admin_balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::CancelMint {},
+        &[],
+    );
+    assert!(res.is_ok());
+    let balance = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        balance.amount,
+        admin_balance.amount + Uint128::new(MINT_PRICE)
+    );
+    let balance = router
+        .wrap()
+        .query_balance(&minter_addr, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(1_000_000));
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert!(res.mint_cancelled);
+    assert!(res.config_frozen);
+
+    // The mint can't be revived
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateStartTime(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 500)),
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Mint configuration is frozen"
+    );
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Mint was cancelled"
+    );
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::MintTo {
+            recipient: buyer.to_string(),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Mint was cancelled"
+    );
+}
+
+#[test]
+fn check_cancel_mint_after_start() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(creator, minter_addr, &ExecuteMsg::CancelMint {}, &[]);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "AlreadyStarted"
+    );
+}
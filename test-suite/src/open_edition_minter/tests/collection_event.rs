@@ -0,0 +1,84 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg2::msg::Sg2ExecuteMsg;
+use sg2::tests::mock_collection_params_1;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, QueryMsg};
+
+use crate::common_setup::setup_minter::common::constants::{CREATION_FEE, DEV_ADDRESS};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::mock_params::mock_create_minter_init_msg;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_collection_instantiated_event() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg.clone()).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+
+    let mut collection_params = mock_collection_params_1(None);
+    collection_params.code_id = vt.code_ids.sg721_code_id;
+    collection_params.info.creator = creator.to_string();
+    let msg = Sg2ExecuteMsg::CreateMinter(mock_create_minter_init_msg(collection_params, init_msg));
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: coins(CREATION_FEE, NATIVE_DENOM),
+        }))
+        .unwrap();
+    let res = router
+        .execute_contract(
+            creator,
+            factory_addr,
+            &msg,
+            &coins(CREATION_FEE, NATIVE_DENOM),
+        )
+        .unwrap();
+
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-collection-instantiated")
+        .unwrap();
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(attribute("minter"), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(attribute("sg721_address"), config.sg721_address);
+}
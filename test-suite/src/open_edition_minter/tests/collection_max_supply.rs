@@ -0,0 +1,88 @@
+use cosmwasm_std::{coins, Coin, Empty, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg721::ExecuteMsg as Sg721ExecuteMsg;
+use sg721_base::msg::{QueryMsg as Sg721QueryMsg, SupplyResponse};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_collection_max_supply_from_num_tokens() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(2),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res: SupplyResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr.clone(), &Sg721QueryMsg::Supply {})
+        .unwrap();
+    assert_eq!(res.max_supply, Some(2));
+
+    for _ in 0..2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    // A minter minting past the edition size is stopped by the collection itself
+    let res = router.execute_contract(
+        minter_addr,
+        collection_addr.clone(),
+        &Sg721ExecuteMsg::<Option<Empty>, Empty>::Mint {
+            token_id: "3".to_string(),
+            owner: buyer.to_string(),
+            token_uri: None,
+            extension: None,
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "MaxSupplyReached"
+    );
+
+    let res: SupplyResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Sg721QueryMsg::Supply {})
+        .unwrap();
+    assert_eq!(res.minted, 2);
+}
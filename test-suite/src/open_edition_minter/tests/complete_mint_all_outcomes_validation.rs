@@ -29,6 +29,11 @@ fn check_mint_revenues_distribution(num_tokens: Option<u32>, end_minter_time: Op
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(3);
     let init_msg = init_msg(
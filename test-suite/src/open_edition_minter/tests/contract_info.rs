@@ -0,0 +1,53 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use open_edition_minter::contract::FEATURES;
+use open_edition_minter::msg::{ContractInfoResponse, QueryMsg};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_contract_info_query() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res: ContractInfoResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::ContractInfo {})
+        .unwrap();
+    assert_eq!(res.contract, "crates.io:sg-open-edition-minter");
+    assert_eq!(res.features.len(), FEATURES.len());
+    assert!(res.features.contains(&"batch_mint".to_string()));
+
+    // Same version as the one cw2 stored at instantiate
+    let version = cw2::query_contract_info(&router.wrap(), minter_addr).unwrap();
+    assert_eq!(res.version, version.version);
+}
@@ -0,0 +1,308 @@
+use cosmwasm_std::{coin, coins, to_json_binary, Coin, Decimal, Timestamp, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw721::{Cw721QueryMsg, OwnerOfResponse};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{
+    ConfigResponse, ExecuteMsg, QueryMsg, QuoteBatchResponse, ReceiveMsg,
+};
+
+use crate::common_setup::contract_boxes::contract_cw20;
+use crate::common_setup::setup_accounts_and_block::{setup_block_time, CREATION_FEE};
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::setup_open_edition_minter;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const CW20_MINT_PRICE: u128 = 50_000_000;
+
+#[test]
+fn check_cw20_payment() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg.clone()).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+
+    let cw20_id = router.store_code(contract_cw20());
+    let cw20_addr = router
+        .instantiate_contract(
+            cw20_id,
+            creator.clone(),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: buyer.to_string(),
+                    amount: Uint128::new(CW20_MINT_PRICE * 10),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20",
+            None,
+        )
+        .unwrap();
+
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+
+    // The price has to be in an actual cw20 token
+    let mut invalid_init_msg = init_msg.clone();
+    invalid_init_msg.cw20_mint_price = Some(Cw20Coin {
+        address: buyer.to_string(),
+        amount: Uint128::new(CW20_MINT_PRICE),
+    });
+    let res = setup_open_edition_minter(&mut router, creator.clone(), invalid_init_msg);
+    assert_eq!(
+        res.error.unwrap().root_cause().to_string(),
+        "Invalid cw20 mint price, must be a non zero amount of a cw20 token"
+    );
+
+    let mut init_msg = init_msg;
+    init_msg.cw20_mint_price = Some(Cw20Coin {
+        address: cw20_addr.to_string(),
+        amount: Uint128::new(CW20_MINT_PRICE),
+    });
+    let res = setup_open_edition_minter(&mut router, creator.clone(), init_msg);
+    let (minter_addr, collection_addr) = (res.minter.unwrap(), res.collection.unwrap());
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.cw20_mint_price.unwrap().address, cw20_addr);
+
+    // There is no native batch to quote
+    let res: Result<QuoteBatchResponse, _> = router.wrap().query_wasm_smart(
+        minter_addr.clone(),
+        &QueryMsg::QuoteBatch {
+            count: 1,
+            address: None,
+        },
+    );
+    assert!(res.unwrap_err().to_string().contains(
+        "Mint is paid in cw20 tokens, send them to the minter through the cw20 contract"
+    ));
+
+    // Native public mints are rejected
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Mint is paid in cw20 tokens, send them to the minter through the cw20 contract"
+    );
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        cw20_addr.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: minter_addr.to_string(),
+            amount: Uint128::new(CW20_MINT_PRICE - 1),
+            msg: to_json_binary(&ReceiveMsg::Mint {}).unwrap(),
+        },
+        &[],
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Incorrect cw20 payment amount, got: 49999999, expected: 50000000"
+    );
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        cw20_addr.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: minter_addr.to_string(),
+            amount: Uint128::new(CW20_MINT_PRICE),
+            msg: to_json_binary(&ReceiveMsg::Mint {}).unwrap(),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: OwnerOfResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.owner, buyer.to_string());
+
+    // 10% network fee to the dev address, the rest to the seller
+    let balance: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            cw20_addr.clone(),
+            &Cw20QueryMsg::Balance {
+                address: DEV_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance.balance, Uint128::new(5_000_000));
+    let balance: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            cw20_addr,
+            &Cw20QueryMsg::Balance {
+                address: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance.balance, Uint128::new(45_000_000));
+
+    // Only the configured token is accepted
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr,
+        &ExecuteMsg::Receive(cw20::Cw20ReceiveMsg {
+            sender: buyer.to_string(),
+            amount: Uint128::new(CW20_MINT_PRICE),
+            msg: to_json_binary(&ReceiveMsg::Mint {}).unwrap(),
+        }),
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        format!("Invalid cw20 token: {buyer}")
+    );
+}
+
+#[test]
+fn check_cw20_payment_splits() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg.clone()).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+
+    let cw20_id = router.store_code(contract_cw20());
+    let cw20_addr = router
+        .instantiate_contract(
+            cw20_id,
+            creator.clone(),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: buyer.to_string(),
+                    amount: Uint128::new(CW20_MINT_PRICE * 10),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20",
+            None,
+        )
+        .unwrap();
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+
+    let mut init_msg = init_msg;
+    init_msg.cw20_mint_price = Some(Cw20Coin {
+        address: cw20_addr.to_string(),
+        amount: Uint128::new(CW20_MINT_PRICE),
+    });
+    init_msg.payment_splits = Some(vec![
+        ("artist1".to_string(), Decimal::percent(70)),
+        ("artist2".to_string(), Decimal::percent(30)),
+    ]);
+    let res = setup_open_edition_minter(&mut router, creator.clone(), init_msg);
+    let minter_addr = res.minter.unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        buyer,
+        cw20_addr.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: minter_addr.to_string(),
+            amount: Uint128::new(CW20_MINT_PRICE),
+            msg: to_json_binary(&ReceiveMsg::Mint {}).unwrap(),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    // The seller share is split like a native mint, nothing goes to the creator
+    for (address, expected) in [
+        ("artist1", 31_500_000u128),
+        ("artist2", 13_500_000),
+        (creator.as_str(), 0),
+    ] {
+        let balance: BalanceResponse = router
+            .wrap()
+            .query_wasm_smart(
+                cw20_addr.clone(),
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(expected));
+    }
+}
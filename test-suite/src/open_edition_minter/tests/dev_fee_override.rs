@@ -0,0 +1,87 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, FeeScheduleResponse, QueryMsg};
+
+use crate::common_setup::helpers::{fair_burn_amounts, FairBurnAmounts};
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const WHITE_LABEL: &str = "white_label";
+
+#[test]
+fn check_dev_fee_address_override() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.dev_fee_address_override = Some(WHITE_LABEL.to_string());
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res: FeeScheduleResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::FeeSchedule {})
+        .unwrap();
+    assert_eq!(res.dev_fee_address, WHITE_LABEL);
+
+    let dev_balance_before = router
+        .wrap()
+        .query_balance(DEV_ADDRESS, NATIVE_DENOM)
+        .unwrap();
+    let res = router
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        fair_burn_amounts(&res),
+        FairBurnAmounts {
+            burned: Uint128::new(5_000_000),
+            dev: Uint128::new(5_000_000),
+        }
+    );
+
+    // The dev share goes to the override, the factory's dev address gets nothing
+    let balance = router
+        .wrap()
+        .query_balance(WHITE_LABEL, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(5_000_000));
+    let dev_balance_after = router
+        .wrap()
+        .query_balance(DEV_ADDRESS, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(dev_balance_before, dev_balance_after);
+}
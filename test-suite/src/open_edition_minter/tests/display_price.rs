@@ -0,0 +1,83 @@
+use cosmwasm_std::{coin, Coin, DenomMetadata, DenomUnit, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{MintPriceResponse, QueryMsg};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_display_price() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(150_500_000, NATIVE_DENOM)),
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // Without denom metadata the raw coin is displayed
+    let res: MintPriceResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::MintPrice {})
+        .unwrap();
+    assert_eq!(res.current_price, coin(150_500_000, NATIVE_DENOM));
+    assert_eq!(res.display_price, format!("150500000{NATIVE_DENOM}"));
+
+    router.init_modules(|router, _, storage| {
+        router
+            .bank
+            .set_denom_metadata(
+                storage,
+                NATIVE_DENOM.to_string(),
+                DenomMetadata {
+                    base: NATIVE_DENOM.to_string(),
+                    display: "stars".to_string(),
+                    denom_units: vec![
+                        DenomUnit {
+                            denom: NATIVE_DENOM.to_string(),
+                            exponent: 0,
+                            aliases: vec![],
+                        },
+                        DenomUnit {
+                            denom: "stars".to_string(),
+                            exponent: 6,
+                            aliases: vec![],
+                        },
+                    ],
+                    ..DenomMetadata::default()
+                },
+            )
+            .unwrap()
+    });
+
+    let res: MintPriceResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintPrice {})
+        .unwrap();
+    assert_eq!(res.current_price, coin(150_500_000, NATIVE_DENOM));
+    assert_eq!(res.display_price, "150.5 stars");
+}
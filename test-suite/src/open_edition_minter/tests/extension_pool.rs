@@ -0,0 +1,143 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::{NftData, NftMetadataType};
+use sg_metadata::Metadata;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_nft_data;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn metadata(name: &str, image: &str) -> Metadata {
+    Metadata {
+        image: Some(image.to_string()),
+        name: Some(name.to_string()),
+        ..Metadata::default()
+    }
+}
+
+fn on_chain_nft_data() -> NftData {
+    NftData {
+        nft_data_type: NftMetadataType::OnChainMetadata,
+        extension: Some(metadata("Base", "https://example.com/base.png")),
+        token_uri: None,
+    }
+}
+
+#[test]
+fn check_extension_pool_mint() {
+    let nft_data = on_chain_nft_data();
+    let mut init_msg = init_msg(
+        nft_data.clone(),
+        Some(3),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.extension_pool = Some(vec![
+        metadata("First", " https://example.com/1.png "),
+        metadata("Second", "https://example.com/2.png"),
+    ]);
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg, nft_data).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // Pool images are validated and trimmed like the base extension image
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        res.extension_pool,
+        vec![
+            metadata("First", "https://example.com/1.png"),
+            metadata("Second", "https://example.com/2.png"),
+        ]
+    );
+
+    // Minting past the pool size cycles back to the start
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    for _ in 0..3 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+}
+
+#[test]
+fn check_invalid_extension_pool() {
+    // Pool images must be valid URLs
+    let nft_data = on_chain_nft_data();
+    let mut init_msg_1 = init_msg(
+        nft_data.clone(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg_1.extension_pool = Some(vec![metadata("First", "not a url")]);
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg_1, nft_data).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        "relative URL without a base"
+    );
+
+    // A pool requires on-chain metadata
+    let mut init_msg_2 = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg_2.extension_pool = Some(vec![metadata("First", "https://example.com/1.png")]);
+    let vt =
+        open_edition_minter_nft_data(params_extension(), init_msg_2, default_nft_data()).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        "InvalidNftDataProvided"
+    );
+}
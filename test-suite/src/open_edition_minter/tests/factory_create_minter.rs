@@ -1,13 +1,18 @@
-use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cosmwasm_std::{coin, Coin, Timestamp, Uint128};
 use open_edition_factory::state::ParamsExtension;
 use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
+use crate::common_setup::contract_boxes::custom_mock_app;
+use crate::common_setup::setup_accounts_and_block::{set_block_time, setup_accounts};
 use crate::common_setup::setup_minter::common::constants::{
     DEV_ADDRESS, MAX_TOKEN_LIMIT, MIN_MINT_PRICE_OPEN_EDITION,
 };
 use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
     default_nft_data, init_msg,
 };
+use crate::common_setup::setup_minter::open_edition_minter::setup::{
+    mint_n_tokens, setup_open_edition_minter,
+};
 use crate::common_setup::templates::{
     open_edition_minter_custom_template, open_edition_minter_nft_data,
     open_edition_minter_start_and_end_time,
@@ -31,6 +36,11 @@ fn check_valid_create_minter() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(5);
     let init_msg = init_msg(
@@ -58,6 +68,11 @@ fn check_custom_denom_create_minter() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(5);
     let init_msg = init_msg(
@@ -90,6 +105,11 @@ fn check_invalid_create_minter_address_limit() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(20);
     let init_msg_1 = init_msg(
@@ -143,6 +163,11 @@ fn check_invalid_create_minter_start_end_time() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let start_time = Some(Timestamp::from_nanos(100_000));
@@ -208,6 +233,11 @@ fn check_invalid_create_minter_mint_price() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg_1 = init_msg(
@@ -266,6 +296,11 @@ fn check_invalid_create_minter_nft_data() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let start_time = Some(Timestamp::from_nanos(100_000));
@@ -385,6 +420,11 @@ fn check_invalid_create_minter_max_tokens() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg_1 = init_msg(
@@ -410,3 +450,33 @@ fn check_invalid_create_minter_max_tokens() {
     let vt = open_edition_minter_custom_template(params_extension, init_msg_2).unwrap();
     assert!(vt.collection_response_vec[0].error.is_some());
 }
+
+#[test]
+fn check_setup_open_edition_minter() {
+    let mut app = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut app);
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let res = setup_open_edition_minter(&mut app, creator, init_msg);
+    assert!(res.error.is_none());
+    assert!(res.collection.is_some());
+
+    set_block_time(
+        &mut app,
+        Timestamp::from_nanos(GENESIS_MINT_START_TIME + 101),
+    );
+    let token_ids = mint_n_tokens(
+        &mut app,
+        &res.minter.unwrap(),
+        &buyer,
+        3,
+        coin(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert_eq!(token_ids, vec!["1", "2", "3"]);
+}
@@ -0,0 +1,205 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::msg::{
+    MintersResponse, QueryMsg as FactoryQueryMsg, StatusBatchResponse,
+};
+use open_edition_factory::state::ParamsExtension;
+use sg2::msg::Sg2ExecuteMsg;
+use sg2::tests::mock_collection_params_1;
+use sg4::{Status, StatusResponse};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{QueryMsg, SudoMsg as MinterSudoMsg, SummaryResponse};
+
+use crate::common_setup::setup_minter::common::constants::{
+    CREATION_FEE, DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::mock_params::mock_create_minter;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_factory_minters_query() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+
+    // A second minter created through the same factory
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 20_000);
+    let mint_price = coins(MIN_MINT_PRICE_OPEN_EDITION * 2, NATIVE_DENOM);
+    let mut msg = mock_create_minter(
+        Some(start_time),
+        Some(end_time),
+        Some(mint_price[0].clone()),
+        Some(1),
+        None,
+        default_nft_data(),
+        mock_collection_params_1(Some(start_time)),
+        None,
+    );
+    msg.collection_params.code_id = vt.code_ids.sg721_code_id;
+    msg.collection_params.info.creator = creator.to_string();
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: coins(CREATION_FEE, NATIVE_DENOM),
+        }))
+        .unwrap();
+    let res = router.execute_contract(
+        creator,
+        factory_addr.clone(),
+        &Sg2ExecuteMsg::CreateMinter(msg),
+        &coins(CREATION_FEE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &FactoryQueryMsg::Minters {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.minters.len(), 2);
+    assert!(res.minters.contains(&minter_addr));
+    let second_minter = res
+        .minters
+        .iter()
+        .find(|minter| **minter != minter_addr)
+        .unwrap()
+        .clone();
+
+    // Pagination picks up after the given address
+    let page: MintersResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &FactoryQueryMsg::Minters {
+                start_after: Some(res.minters[0].to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(page.minters, vec![res.minters[1].clone()]);
+
+    let summary: SummaryResponse = router
+        .wrap()
+        .query_wasm_smart(second_minter, &QueryMsg::Summary {})
+        .unwrap();
+    assert_eq!(
+        summary,
+        SummaryResponse {
+            start_time,
+            end_time: Some(end_time),
+            mint_price: mint_price[0].clone(),
+            num_tokens: None,
+            total_minted: 0,
+        }
+    );
+}
+
+#[test]
+fn check_factory_status_batch_query() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+
+    let res = router.wasm_sudo(
+        minter_addr.clone(),
+        &MinterSudoMsg::UpdateStatus {
+            is_verified: true,
+            is_blocked: false,
+            is_explicit: true,
+        },
+    );
+    assert!(res.is_ok());
+
+    let res: StatusBatchResponse = router
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &FactoryQueryMsg::StatusBatch {
+                minters: vec![minter_addr.to_string()],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.statuses,
+        vec![(
+            minter_addr.clone(),
+            StatusResponse {
+                status: Status {
+                    is_verified: true,
+                    is_blocked: false,
+                    is_explicit: true,
+                },
+            },
+        )]
+    );
+
+    // Batches are bounded
+    let res: Result<StatusBatchResponse, _> = router.wrap().query_wasm_smart(
+        factory_addr,
+        &FactoryQueryMsg::StatusBatch {
+            minters: vec![minter_addr.to_string(); 51],
+        },
+    );
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Status batch is limited to 50 minters"));
+}
@@ -0,0 +1,86 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::msg::OpenEditionUpdateParamsExtension;
+use open_edition_factory::state::{OpenEditionMinterParams, ParamsExtension};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::QueryMsg;
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::sudo_update_params;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_factory_params_query() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension.clone(), init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res: OpenEditionMinterParams = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::FactoryParams {})
+        .unwrap();
+    assert_eq!(res.extension, params_extension);
+
+    // Param updates are visible right away
+    let update_msg = sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: Some(500),
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            min_mint_price: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
+        },
+    };
+    sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(update_msg),
+    );
+
+    let res: OpenEditionMinterParams = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::FactoryParams {})
+        .unwrap();
+    assert_eq!(res.mint_fee_bps, 500);
+}
@@ -0,0 +1,83 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::helpers::{fair_burn_amounts, FairBurnAmounts};
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_mint_fair_burn_amounts() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Public mints pay the 10% mint fee, half burned and half to the dev
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        fair_burn_amounts(&res),
+        FairBurnAmounts {
+            burned: Uint128::new(5_000_000),
+            dev: Uint128::new(5_000_000),
+        }
+    );
+
+    // Airdrops pay the 1% airdrop fee on the airdrop price
+    let res = router
+        .execute_contract(
+            creator,
+            minter_addr,
+            &ExecuteMsg::MintTo {
+                recipient: buyer.to_string(),
+            },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        fair_burn_amounts(&res),
+        FairBurnAmounts {
+            burned: Uint128::new(500_000),
+            dev: Uint128::new(500_000),
+        }
+    );
+}
@@ -0,0 +1,77 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::{OpenEditionMinterParams, ParamsExtension};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{FeeScheduleResponse, QueryMsg, SudoMsg};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_fee_schedule_query() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: Some(500),
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let factory_params: OpenEditionMinterParams = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::FactoryParams {})
+        .unwrap();
+    let res: FeeScheduleResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::FeeSchedule {})
+        .unwrap();
+    assert_eq!(
+        res,
+        FeeScheduleResponse {
+            mint_fee_bps: factory_params.mint_fee_bps,
+            airdrop_mint_fee_bps: 100,
+            dev_fee_address: DEV_ADDRESS.to_string(),
+            min_mint_price: factory_params.min_mint_price,
+            creation_fee: factory_params.creation_fee,
+        }
+    );
+
+    // Verified minters report the reduced mint fee
+    router
+        .wasm_sudo(
+            minter_addr.clone(),
+            &SudoMsg::UpdateStatus {
+                is_verified: true,
+                is_blocked: false,
+                is_explicit: false,
+            },
+        )
+        .unwrap();
+    let res: FeeScheduleResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::FeeSchedule {})
+        .unwrap();
+    assert_eq!(res.mint_fee_bps, 500);
+}
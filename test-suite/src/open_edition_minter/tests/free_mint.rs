@@ -0,0 +1,141 @@
+use cosmwasm_std::{coin, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_zero_price_requires_free_mint() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(0, NATIVE_DENOM)),
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    assert!(vt.collection_response_vec[0].error.is_some());
+}
+
+#[test]
+fn check_free_mint() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(0, NATIVE_DENOM)),
+    );
+    init_msg.free_mint = Some(true);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert!(res.free_mint);
+    assert_eq!(res.mint_price, coin(0, NATIVE_DENOM));
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    let balance_before = router.wrap().query_all_balances(&buyer).unwrap();
+    let res = router
+        .execute_contract(buyer.clone(), minter_addr, &ExecuteMsg::Mint {}, &[])
+        .unwrap();
+    let attribute = |key: &str| {
+        res.events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(
+        attribute("seller_amount"),
+        coin(0, NATIVE_DENOM).to_string()
+    );
+    assert_eq!(attribute("network_fee"), coin(0, NATIVE_DENOM).to_string());
+    // Only gas is paid
+    let balance_after = router.wrap().query_all_balances(&buyer).unwrap();
+    assert_eq!(balance_before, balance_after);
+}
+
+#[test]
+fn check_update_mint_price_to_zero() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.free_mint = Some(true);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg.clone()).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateMintPrice { price: 0 },
+        &[],
+    );
+    assert!(res.is_ok());
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.mint_price, coin(0, NATIVE_DENOM));
+
+    // The floor still applies to other minters
+    init_msg.free_mint = None;
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::UpdateMintPrice { price: 0 },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        format!(
+            "Minimum network mint price {} got 0",
+            MIN_MINT_PRICE_OPEN_EDITION
+        )
+    );
+}
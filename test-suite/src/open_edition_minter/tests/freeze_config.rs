@@ -0,0 +1,107 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_freeze_config() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(end_time),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::FreezeConfig {},
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::FreezeConfig {},
+        &[],
+    );
+    assert!(res.is_ok());
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert!(config.config_frozen);
+
+    // Updates are rejected for good
+    let updates = [
+        ExecuteMsg::UpdateMintPrice { price: 50_000_000 },
+        ExecuteMsg::UpdateEndTime(end_time.plus_nanos(1_000)),
+        ExecuteMsg::UpdatePerAddressLimit {
+            per_address_limit: 2,
+        },
+        ExecuteMsg::UpdateStartTradingTime(None),
+        ExecuteMsg::FreezeConfig {},
+    ];
+    for update in updates {
+        let res = router.execute_contract(creator.clone(), minter_addr.clone(), &update, &[]);
+        assert_eq!(
+            res.err().unwrap().source().unwrap().to_string(),
+            "Mint configuration is frozen"
+        );
+    }
+
+    // Minting is unaffected
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::MintTo {
+            recipient: buyer.to_string(),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+}
@@ -27,6 +27,11 @@ fn frozen_factory_cannot_create_new_minters() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let init_msg = init_msg(
         default_nft_data(),
@@ -58,6 +63,11 @@ fn frozen_factory_cannot_create_new_minters() {
             airdrop_mint_fee_bps: None,
             airdrop_mint_price: None,
             dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
         },
     };
 
@@ -1,7 +1,7 @@
 use cosmwasm_std::{coin, Addr, Coin, Decimal, Timestamp, Uint128};
 use cw_multi_test::{BankSudo, Executor, SudoMsg};
 use open_edition_factory::state::{OpenEditionMinterParams, ParamsExtension};
-use open_edition_minter::msg::ExecuteMsg;
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, RevenueResponse};
 use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
 
 use crate::common_setup::{
@@ -29,6 +29,11 @@ fn check_custom_create_minter_denom() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg = init_msg(
@@ -53,6 +58,11 @@ fn check_custom_create_minter_denom() {
             airdrop_mint_fee_bps: 100,
             dev_fee_address: DEV_ADDRESS.to_string(),
             airdrop_mint_price: params_extension.airdrop_mint_price.clone(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     };
     let vt =
@@ -74,7 +84,12 @@ fn check_custom_create_minter_denom() {
     setup_block_time(&mut router, GENESIS_MINT_START_TIME + 100, None);
     //     // Mint succeeds
     let mint_msg = ExecuteMsg::Mint {};
-    let res = router.execute_contract(buyer.clone(), minter_addr, &mint_msg, &[mint_price.clone()]);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &mint_msg,
+        &[mint_price.clone()],
+    );
     assert!(res.is_ok());
 
     // confirm balances
@@ -86,6 +101,13 @@ fn check_custom_create_minter_denom() {
     let seller_amount = mint_price.amount.checked_sub(network_fee).unwrap();
     let balance = router.wrap().query_balance(creator, denom).unwrap();
     assert_eq!(balance.amount, seller_amount);
+
+    // Revenue is reported in the denom the buyer paid
+    let res: RevenueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Revenue {})
+        .unwrap();
+    assert_eq!(res.seller_amount, vec![coin(seller_amount.u128(), denom)]);
     // all mint goes to fairburn_pool confirmed in e2e test
 }
 
@@ -107,6 +129,11 @@ fn one_hundred_percent_burned_ibc_minter() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg = init_msg(
@@ -132,6 +159,11 @@ fn one_hundred_percent_burned_ibc_minter() {
             airdrop_mint_fee_bps: 100,
             dev_fee_address: DEV_ADDRESS.to_string(),
             airdrop_mint_price: params_extension.airdrop_mint_price.clone(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     };
     let vt =
@@ -191,6 +223,11 @@ fn zero_mint_fee() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     let per_address_limit_minter = Some(2);
     let init_msg = init_msg(
@@ -216,6 +253,11 @@ fn zero_mint_fee() {
             airdrop_mint_fee_bps: 100,
             dev_fee_address: DEV_ADDRESS.to_string(),
             airdrop_mint_price: params_extension.airdrop_mint_price.clone(),
+            max_batch_mint_size: 50,
+            allowed_denoms: vec![],
+            platform_fee_bps: None,
+            platform_fee_address: None,
+            verified_mint_fee_bps: None,
         },
     };
     let vt =
@@ -0,0 +1,328 @@
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
+use cw721::ContractInfoResponse;
+use cw_multi_test::Executor;
+use open_edition_factory::msg::{OpenEditionMinterCreateMsg, OpenEditionUpdateParamsExtension};
+use open_edition_factory::state::ParamsExtension;
+use open_edition_minter::msg::{ConfigResponse, QueryMsg};
+use sg2::tests::mock_collection_params_1;
+use sg721::RoyaltyInfoResponse;
+use sg721_base::msg::QueryMsg as Sg721QueryMsg;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::mock_params::mock_create_minter_init_msg;
+use crate::common_setup::setup_minter::open_edition_minter::setup::sudo_update_params;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+fn create_msg(
+    sg721_code_id: u64,
+    start_time: Timestamp,
+    end_time: Timestamp,
+) -> OpenEditionMinterCreateMsg {
+    let mut collection_params = mock_collection_params_1(None);
+    collection_params.code_id = sg721_code_id;
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        Some(start_time),
+        Some(end_time),
+        None,
+        None,
+    );
+    mock_create_minter_init_msg(collection_params, init_msg)
+}
+
+#[test]
+fn check_minter_instantiate_times() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let (minter_code_id, sg721_code_id) = (vt.code_ids.minter_code_id, vt.code_ids.sg721_code_id);
+
+    // The minter is instantiated directly, bypassing the factory validation
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr.clone(),
+        &create_msg(sg721_code_id, start_time, start_time),
+        &[],
+        "minter",
+        None,
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        format!("InvalidEndTime {} < {}", start_time, start_time)
+    );
+
+    let end_time = start_time.plus_seconds(1_000);
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr.clone(),
+        &create_msg(sg721_code_id, start_time, end_time),
+        &[],
+        "minter",
+        None,
+    );
+    assert!(res.is_ok());
+
+    // Start time too far in the past
+    let block_time = start_time.plus_seconds(61);
+    setup_block_time(&mut router, block_time.nanos(), None);
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr,
+        &create_msg(sg721_code_id, start_time, end_time),
+        &[],
+        "minter",
+        None,
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        format!("InvalidStartTime {} < {}", start_time, block_time)
+    );
+}
+
+#[test]
+fn check_collection_name_length() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let (minter_code_id, sg721_code_id) = (vt.code_ids.minter_code_id, vt.code_ids.sg721_code_id);
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let end_time = start_time.plus_seconds(1_000);
+
+    // 65 emojis of 4 bytes each exceed the 256 bytes limit
+    let mut msg = create_msg(sg721_code_id, start_time, end_time);
+    msg.collection_params.name = "\u{1F680}".repeat(65);
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr.clone(),
+        &msg,
+        &[],
+        "minter",
+        None,
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        "Collection name too long"
+    );
+
+    // The full name is kept by the collection, only the label is truncated
+    let name = "\u{1F680}".repeat(40);
+    let mut msg = create_msg(sg721_code_id, start_time, end_time);
+    msg.collection_params.name = name.clone();
+    let minter_addr = router
+        .instantiate_contract(minter_code_id, factory_addr, &msg, &[], "minter", None)
+        .unwrap();
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Config {})
+        .unwrap();
+    let collection_info: ContractInfoResponse = router
+        .wrap()
+        .query_wasm_smart(
+            config.sg721_address.clone(),
+            &Sg721QueryMsg::ContractInfo {},
+        )
+        .unwrap();
+    assert_eq!(collection_info.name, name);
+    let contract_data = router
+        .contract_data(&Addr::unchecked(config.sg721_address))
+        .unwrap();
+    assert_eq!(
+        contract_data.label,
+        format!("SG721-{}", "\u{1F680}".repeat(30))
+    );
+}
+
+#[test]
+fn check_royalty_share() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let (minter_code_id, sg721_code_id) = (vt.code_ids.minter_code_id, vt.code_ids.sg721_code_id);
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let end_time = start_time.plus_seconds(1_000);
+
+    // Fails in the minter itself rather than in the sg721 reply
+    let mut msg = create_msg(sg721_code_id, start_time, end_time);
+    msg.collection_params.info.royalty_info = Some(RoyaltyInfoResponse {
+        payment_address: "creator".to_string(),
+        share: Decimal::percent(11),
+    });
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr.clone(),
+        &msg,
+        &[],
+        "minter",
+        None,
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        "Royalty share cannot be greater than 10%"
+    );
+
+    let mut msg = create_msg(sg721_code_id, start_time, end_time);
+    msg.collection_params.info.royalty_info = Some(RoyaltyInfoResponse {
+        payment_address: "creator".to_string(),
+        share: Decimal::percent(10),
+    });
+    let res = router.instantiate_contract(minter_code_id, factory_addr, &msg, &[], "minter", None);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn check_mint_price_denom() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let (minter_code_id, sg721_code_id) = (vt.code_ids.minter_code_id, vt.code_ids.sg721_code_id);
+
+    // The minter is instantiated directly, bypassing the factory validation
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let mut msg = create_msg(sg721_code_id, start_time, start_time.plus_seconds(1_000));
+    msg.init_msg.mint_price.denom = "uatom".to_string();
+    let res = router.instantiate_contract(
+        minter_code_id,
+        factory_addr.clone(),
+        &msg,
+        &[],
+        "minter",
+        None,
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        format!("InvalidDenom {} got uatom", NATIVE_DENOM)
+    );
+
+    // Denoms allowed by the factory are accepted
+    let update_msg = sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            min_mint_price: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: Some(vec![NATIVE_DENOM.to_string(), "uatom".to_string()]),
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
+        },
+    };
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(update_msg),
+    );
+    assert!(res[0].is_ok());
+    let res = router.instantiate_contract(minter_code_id, factory_addr, &msg, &[], "minter", None);
+    assert!(res.is_ok());
+}
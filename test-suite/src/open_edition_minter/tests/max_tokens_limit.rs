@@ -26,6 +26,11 @@ fn check_max_tokens_limit_init() {
             amount: Uint128::new(100_000_000u128),
         },
         dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
     };
     // if the number of tokens to be minted exceed to max, should error
     let per_address_limit_minter = Some(2);
@@ -90,3 +95,109 @@ fn check_max_tokens_limit_init() {
     );
     assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
 }
+
+#[test]
+fn check_sold_out_race() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(1),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Both race for the last token, the first one wins
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let balances_before = router.wrap().query_all_balances(&creator).unwrap();
+    let minter_balances_before = router.wrap().query_all_balances(&minter_addr).unwrap();
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+
+    // No funds moved for the loser
+    let balances_after = router.wrap().query_all_balances(&creator).unwrap();
+    assert_eq!(balances_before, balances_after);
+    let minter_balances_after = router.wrap().query_all_balances(&minter_addr).unwrap();
+    assert_eq!(minter_balances_before, minter_balances_after);
+}
+
+#[test]
+fn check_admin_mint_to_sold_out() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(1),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Airdrops can't go past the advertised supply either
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::MintTo {
+            recipient: buyer.to_string(),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+}
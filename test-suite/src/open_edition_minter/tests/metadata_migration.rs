@@ -0,0 +1,161 @@
+use cosmwasm_std::{coins, Addr, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::NftMetadataType;
+use sg_metadata::Metadata;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, MigrateMsg, QueryMsg};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+// Pretend the minter was instantiated by an older release so migrate runs
+fn downgrade_version(router: &mut App, minter_addr: &Addr) {
+    let mut storage = router.contract_storage_mut(minter_addr);
+    cw2::set_contract_version(
+        storage.as_mut(),
+        "crates.io:sg-open-edition-minter",
+        "0.1.0",
+    )
+    .unwrap();
+}
+
+// Only collections on the on-chain metadata code can take `Metadata` mints
+fn set_collection_contract(router: &mut App, collection_addr: &Addr, contract: &str) {
+    let mut storage = router.contract_storage_mut(collection_addr);
+    cw2::set_contract_version(storage.as_mut(), contract, "3.5.0").unwrap();
+}
+
+fn migrate_msg(image: &str, force: Option<bool>) -> MigrateMsg {
+    MigrateMsg::ToOnChainMetadata {
+        extension: Metadata {
+            image: Some(image.to_string()),
+            name: Some("On chain".to_string()),
+            ..Metadata::default()
+        },
+        force,
+    }
+}
+
+#[test]
+fn check_migrate_to_on_chain_metadata() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let code_id = router
+        .wrap()
+        .query_wasm_contract_info(minter_addr.clone())
+        .unwrap()
+        .code_id;
+
+    // An off-chain sg721-base collection can't store the metadata
+    downgrade_version(&mut router, &minter_addr);
+    let res = router.migrate_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &migrate_msg("https://example.com/1.png", None),
+        code_id,
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "The collection does not store on-chain metadata"
+    );
+
+    // The image has to be a valid URL
+    set_collection_contract(
+        &mut router,
+        &collection_addr,
+        "crates.io:sg721-metadata-onchain",
+    );
+    let res = router.migrate_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &migrate_msg("not a url", None),
+        code_id,
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "relative URL without a base"
+    );
+
+    let res = router.migrate_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &migrate_msg(" https://example.com/1.png ", None),
+        code_id,
+    );
+    assert!(res.is_ok());
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.nft_data.nft_data_type, NftMetadataType::OnChainMetadata);
+    assert_eq!(res.nft_data.token_uri, None);
+    assert_eq!(
+        res.nft_data.extension.unwrap().image,
+        Some("https://example.com/1.png".to_string())
+    );
+
+    // Once tokens are minted the conversion has to be forced
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    downgrade_version(&mut router, &minter_addr);
+    let res = router.migrate_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &migrate_msg("https://example.com/2.png", None),
+        code_id,
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Tokens were already minted, pass force to convert the metadata type"
+    );
+
+    let res = router.migrate_contract(
+        creator,
+        minter_addr,
+        &migrate_msg("https://example.com/2.png", Some(true)),
+        code_id,
+    );
+    assert!(res.is_ok());
+}
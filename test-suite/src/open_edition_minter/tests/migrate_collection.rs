@@ -0,0 +1,159 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128, WasmMsg};
+use cw_multi_test::Executor;
+use open_edition_factory::msg::{
+    OpenEditionUpdateParamsExtension, OpenEditionUpdateParamsMsg, SudoMsg as FactorySudoMsg,
+};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, QueryMsg, SudoMsg};
+
+use crate::common_setup::contract_boxes::contract_sg721_updatable;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_migrate_collection() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let factory_addr = vt.collection_response_vec[0].factory.clone().unwrap();
+    let updatable_code_id = router.store_code(contract_sg721_updatable());
+
+    // The minter administers the collection
+    let contract_data = router.contract_data(&collection_addr).unwrap();
+    assert_eq!(contract_data.admin, Some(minter_addr.clone()));
+    assert_eq!(contract_data.code_id, vt.code_ids.sg721_code_id);
+
+    // Code ids not allowed by the factory are rejected
+    let res = router.wasm_sudo(
+        minter_addr.clone(),
+        &SudoMsg::MigrateCollection {
+            new_code_id: updatable_code_id,
+        },
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        format!(
+            "Collection code id {} is not allowed by the factory",
+            updatable_code_id
+        )
+    );
+
+    // Governance allows the updatable code id on the factory
+    let update_msg = OpenEditionUpdateParamsMsg {
+        add_sg721_code_ids: Some(vec![updatable_code_id]),
+        rm_sg721_code_ids: None,
+        frozen: None,
+        code_id: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            max_per_address_limit: None,
+            min_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            airdrop_mint_price: None,
+            dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
+        },
+    };
+    let res = router.wasm_sudo(
+        factory_addr,
+        &FactorySudoMsg::UpdateParams(Box::new(update_msg)),
+    );
+    assert!(res.is_ok());
+
+    let res = router.wasm_sudo(
+        minter_addr.clone(),
+        &SudoMsg::MigrateCollection {
+            new_code_id: updatable_code_id,
+        },
+    );
+    assert!(res.is_ok());
+
+    let contract_data = router.contract_data(&collection_addr).unwrap();
+    assert_eq!(contract_data.code_id, updatable_code_id);
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.sg721_code_id, updatable_code_id);
+}
+
+#[test]
+fn check_migrate_collection_administered_by_creator() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+
+    // Collections from earlier releases were instantiated with the creator as admin
+    router
+        .execute(
+            minter_addr.clone(),
+            WasmMsg::UpdateAdmin {
+                contract_addr: collection_addr.to_string(),
+                admin: creator.to_string(),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    let res = router.wasm_sudo(
+        minter_addr,
+        &SudoMsg::MigrateCollection {
+            new_code_id: vt.code_ids.sg721_code_id,
+        },
+    );
+    assert_eq!(
+        res.err().unwrap().root_cause().to_string(),
+        "The minter is not the admin of its collection"
+    );
+    let contract_data = router.contract_data(&collection_addr).unwrap();
+    assert_eq!(contract_data.admin, Some(creator));
+}
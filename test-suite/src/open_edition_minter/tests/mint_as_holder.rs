@@ -0,0 +1,106 @@
+use cosmwasm_std::{coin, coins, Coin, Timestamp, Uint128};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_accounts_and_block::{setup_block_time, CREATION_FEE};
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::setup_open_edition_minter;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_mint_as_holder() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg_partner = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg_partner).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let partner_minter = vt.collection_response_vec[0].minter.clone().unwrap();
+    let partner_collection = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // The buyer holds a token of the partner collection
+    let res = router.execute_contract(
+        buyer.clone(),
+        partner_minter,
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 200)),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.allowed_holder_collections = Some(vec![partner_collection.to_string()]);
+    let minter_addr = setup_open_edition_minter(&mut router, creator.clone(), init_msg)
+        .minter
+        .unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 201, None);
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::MintAsHolder {},
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Sender doesn't hold any of the allowed collections"
+    );
+
+    // Holders mint for free, keeping their partner token
+    let balance_before = router.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintAsHolder {},
+        &[],
+    );
+    assert!(res.is_ok());
+    let balance_after = router.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    assert_eq!(balance_before, balance_after);
+
+    let res = router.execute_contract(buyer, minter_addr, &ExecuteMsg::MintAsHolder {}, &[]);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Holder free mint already claimed"
+    );
+}
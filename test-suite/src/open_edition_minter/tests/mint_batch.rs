@@ -0,0 +1,255 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::msg::OpenEditionUpdateParamsExtension;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, TotalMintCountResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::sudo_update_params;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_mint_batch() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Governance lowers the max batch size
+    let update_msg = sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            min_mint_price: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            dev_fee_address: None,
+            max_batch_mint_size: Some(3),
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps: None,
+            platform_fee_address: None,
+        },
+    };
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(update_msg),
+    );
+    assert!(res[0].is_ok());
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 4,
+            best_effort: None,
+        },
+        &coins(MINT_PRICE * 4, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Batch too large, max: 3"
+    );
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 0,
+            best_effort: None,
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "InvalidNumTokens 3, min: 1"
+    );
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 3,
+            best_effort: None,
+        },
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Only 2 left under the per address limit
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 3,
+            best_effort: None,
+        },
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+}
+
+#[test]
+fn check_mint_batch_best_effort() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(3),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Without the flag the whole batch fails past the supply cap
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 5,
+            best_effort: None,
+        },
+        &coins(MINT_PRICE * 5, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+
+    // The full batch has to be paid upfront
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 5,
+            best_effort: Some(true),
+        },
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        format!(
+            "IncorrectPaymentAmount {}{} != {}{}",
+            MINT_PRICE * 3,
+            NATIVE_DENOM,
+            MINT_PRICE * 5,
+            NATIVE_DENOM
+        )
+    );
+
+    // Only the 3 remaining tokens are minted, the 2 others are refunded
+    let balance = router.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::MintBatch {
+                count: 5,
+                best_effort: Some(true),
+            },
+            &coins(MINT_PRICE * 5, NATIVE_DENOM),
+        )
+        .unwrap();
+    let attribute = |key: &str| {
+        res.events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+    assert_eq!(attribute("minted_count"), "3");
+    assert_eq!(
+        attribute("refund"),
+        format!("{}{}", MINT_PRICE * 2, NATIVE_DENOM)
+    );
+    let new_balance = router.wrap().query_balance(&buyer, NATIVE_DENOM).unwrap();
+    assert_eq!(
+        balance.amount - new_balance.amount,
+        Uint128::from(MINT_PRICE * 3)
+    );
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+
+    // Nothing left to mint
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::MintBatch {
+            count: 1,
+            best_effort: Some(true),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+}
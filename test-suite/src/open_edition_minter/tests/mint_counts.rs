@@ -0,0 +1,89 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, MintCountResponse, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_mint_counts() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    for _ in 0..2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    let res: Vec<MintCountResponse> = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::MintCounts {
+                addresses: vec![buyer.to_string(), creator.to_string()],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![
+            MintCountResponse {
+                address: buyer.to_string(),
+                count: 2,
+            },
+            MintCountResponse {
+                address: creator.to_string(),
+                count: 0,
+            },
+        ]
+    );
+
+    // The number of addresses per query is capped
+    let addresses = (0..101).map(|i| format!("addr{i:04}")).collect();
+    let res: Result<Vec<MintCountResponse>, _> = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::MintCounts { addresses });
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Too many addresses, max 100"));
+}
@@ -0,0 +1,108 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw721::{Cw721QueryMsg, OwnerOfResponse};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, MintCountResponse, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const COLD_WALLET: &str = "cold_wallet";
+
+#[test]
+fn check_mint_for() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(1),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::MintFor {
+                recipient: COLD_WALLET.to_string(),
+            },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    let attrs: Vec<_> = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .collect();
+    assert!(attrs
+        .iter()
+        .any(|attr| attr.key == "sender" && attr.value == buyer.as_str()));
+    assert!(attrs
+        .iter()
+        .any(|attr| attr.key == "recipient" && attr.value == COLD_WALLET));
+
+    let res: OwnerOfResponse = router
+        .wrap()
+        .query_wasm_smart(
+            collection_addr,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.owner, COLD_WALLET);
+
+    // The mint counts against the sender, not the recipient
+    let res: MintCountResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::MintCount {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.count, 1);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::MintFor {
+            recipient: "other_wallet".to_string(),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+}
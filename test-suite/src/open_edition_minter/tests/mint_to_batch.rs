@@ -0,0 +1,196 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, TotalMintCountResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const AIRDROP_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_mint_to_batch() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(4),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let recipients = vec![
+        "recipient1".to_string(),
+        "recipient2".to_string(),
+        "recipient3".to_string(),
+    ];
+
+    // Only the admin can airdrop
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::MintToBatch {
+            recipients: recipients.clone(),
+        },
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintToBatch { recipients: vec![] },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "No recipients provided"
+    );
+
+    // The airdrop price is due for every token
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintToBatch {
+            recipients: recipients.clone(),
+        },
+        &coins(AIRDROP_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_err());
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintToBatch { recipients },
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+
+    // A batch crossing the token limit is rejected as a whole
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::MintToBatch {
+            recipients: vec!["recipient4".to_string(), "recipient5".to_string()],
+        },
+        &coins(AIRDROP_PRICE * 2, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+}
+
+#[test]
+fn check_mint_to_weighted() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(5),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintToWeighted { recipients: vec![] },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "No recipients provided"
+    );
+
+    let res = router
+        .execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::MintToWeighted {
+                recipients: vec![("recipient1".to_string(), 2), ("recipient2".to_string(), 1)],
+            },
+            &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+        )
+        .unwrap();
+    let minted_count = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "minted_count")
+        .unwrap();
+    assert_eq!(minted_count.value, "3");
+
+    // Only 2 tokens left
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::MintToWeighted {
+            recipients: vec![("recipient3".to_string(), 3)],
+        },
+        &coins(AIRDROP_PRICE * 3, NATIVE_DENOM),
+    );
+    assert_eq!(res.err().unwrap().source().unwrap().to_string(), "Sold out");
+
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 3);
+}
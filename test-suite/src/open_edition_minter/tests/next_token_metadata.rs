@@ -0,0 +1,136 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::{NftData, NftMetadataType};
+use sg_metadata::Metadata;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, NextTokenMetadataResponse, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::{
+    open_edition_minter_custom_template, open_edition_minter_nft_data,
+};
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn metadata(name: &str, image: &str) -> Metadata {
+    Metadata {
+        image: Some(image.to_string()),
+        name: Some(name.to_string()),
+        ..Metadata::default()
+    }
+}
+
+#[test]
+fn check_next_token_metadata_on_chain() {
+    let nft_data = NftData {
+        nft_data_type: NftMetadataType::OnChainMetadata,
+        extension: Some(metadata("Base", "https://example.com/base.png")),
+        token_uri: None,
+    };
+    let mut init_msg = init_msg(
+        nft_data.clone(),
+        Some(3),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.extension_pool = Some(vec![
+        metadata("First", "https://example.com/1.png"),
+        metadata("Second", "https://example.com/2.png"),
+    ]);
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg, nft_data).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    for (token_id, expected) in [
+        ("1", metadata("First", "https://example.com/1.png")),
+        ("2", metadata("Second", "https://example.com/2.png")),
+        ("3", metadata("First", "https://example.com/1.png")),
+    ] {
+        let res: NextTokenMetadataResponse = router
+            .wrap()
+            .query_wasm_smart(minter_addr.clone(), &QueryMsg::NextTokenMetadata {})
+            .unwrap();
+        assert_eq!(
+            res,
+            NextTokenMetadataResponse {
+                token_id: token_id.to_string(),
+                extension: Some(expected),
+                token_uri: None,
+            }
+        );
+
+        // The preview is what the next mint assigns
+        let res = router
+            .execute_contract(
+                buyer.clone(),
+                minter_addr.clone(),
+                &ExecuteMsg::Mint {},
+                &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+            )
+            .unwrap();
+        let minted_token_id = res
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attr| attr.key == "token_id")
+            .unwrap();
+        assert_eq!(minted_token_id.value, token_id);
+    }
+}
+
+#[test]
+fn check_next_token_metadata_off_chain() {
+    let nft_data = default_nft_data();
+    let mut init_msg = init_msg(
+        nft_data.clone(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.token_id_prefix = Some("edition".to_string());
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let res: NextTokenMetadataResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::NextTokenMetadata {})
+        .unwrap();
+    assert_eq!(
+        res,
+        NextTokenMetadataResponse {
+            token_id: "edition-1".to_string(),
+            extension: None,
+            token_uri: nft_data.token_uri,
+        }
+    );
+}
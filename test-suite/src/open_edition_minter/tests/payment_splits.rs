@@ -0,0 +1,99 @@
+use cosmwasm_std::{coins, Coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{DEV_ADDRESS, MINT_FEE_FAIR_BURN};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10_000,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_invalid_payment_splits() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.payment_splits = Some(vec![
+        ("artist1".to_string(), Decimal::percent(70)),
+        ("artist2".to_string(), Decimal::percent(20)),
+    ]);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        "Payment splits must not be empty and their shares must sum to 1"
+    );
+}
+
+#[test]
+fn check_mint_payment_splits() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.payment_splits = Some(vec![
+        ("artist1".to_string(), Decimal::percent(70)),
+        ("artist2".to_string(), Decimal::percent(30)),
+    ]);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let seller_amount = MINT_PRICE - MINT_PRICE * MINT_FEE_FAIR_BURN as u128 / 10_000;
+    let balance = router
+        .wrap()
+        .query_balance("artist1", NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount.u128(), seller_amount * 70 / 100);
+    let balance = router
+        .wrap()
+        .query_balance("artist2", NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount.u128(), seller_amount * 30 / 100);
+}
@@ -0,0 +1,239 @@
+use cosmwasm_std::{coin, coins, Coin, Timestamp, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+use open_edition_factory::msg::{OpenEditionUpdateParamsExtension, OpenEditionUpdateParamsMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::sudo_update_params;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const PLATFORM: &str = "platform";
+
+fn params_extension(platform_fee_address: &str) -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: Some(500),
+        platform_fee_address: Some(platform_fee_address.to_string()),
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn attribute(res: &AppResponse, key: &str) -> Option<String> {
+    res.events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.clone())
+}
+
+fn update_platform_fee(
+    platform_fee_bps: Option<u64>,
+    platform_fee_address: Option<String>,
+) -> OpenEditionUpdateParamsMsg {
+    sg2::msg::UpdateMinterParamsMsg {
+        code_id: None,
+        add_sg721_code_ids: None,
+        rm_sg721_code_ids: None,
+        frozen: None,
+        creation_fee: None,
+        min_mint_price: None,
+        mint_fee_bps: None,
+        max_trading_offset_secs: None,
+        extension: OpenEditionUpdateParamsExtension {
+            max_token_limit: None,
+            min_mint_price: None,
+            max_per_address_limit: None,
+            airdrop_mint_price: None,
+            airdrop_mint_fee_bps: None,
+            dev_fee_address: None,
+            max_batch_mint_size: None,
+            allowed_denoms: None,
+            verified_mint_fee_bps: None,
+            platform_fee_bps,
+            platform_fee_address,
+        },
+    }
+}
+
+#[test]
+fn check_platform_fee() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(PLATFORM), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    // 10% network fee and 5% platform fee, the seller gets the rest
+    assert_eq!(
+        attribute(&res, "platform_fee"),
+        Some(format!("5000000{}", NATIVE_DENOM))
+    );
+    assert_eq!(
+        attribute(&res, "seller_amount"),
+        Some(format!("85000000{}", NATIVE_DENOM))
+    );
+
+    let balance = router.wrap().query_balance(PLATFORM, NATIVE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(5_000_000));
+}
+
+#[test]
+fn check_invalid_platform_fee_address() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(""), init_msg).unwrap();
+    assert!(vt.collection_response_vec[0].error.is_some());
+}
+
+#[test]
+fn check_platform_fee_rounding_and_airdrops() {
+    let mint_price = MINT_PRICE + 10;
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(mint_price, NATIVE_DENOM)),
+    );
+    let vt = open_edition_minter_custom_template(params_extension(PLATFORM), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Rounded half up like the network fee
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(mint_price, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        attribute(&res, "platform_fee"),
+        Some(format!("5000001{}", NATIVE_DENOM))
+    );
+    assert_eq!(
+        attribute(&res, "seller_amount"),
+        Some(format!("85000008{}", NATIVE_DENOM))
+    );
+
+    // Admin airdrops don't pay the platform fee
+    let res = router
+        .execute_contract(
+            creator,
+            minter_addr,
+            &ExecuteMsg::MintTo {
+                recipient: buyer.to_string(),
+            },
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(attribute(&res, "platform_fee"), None);
+    let balance = router.wrap().query_balance(PLATFORM, NATIVE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(5_000_001));
+}
+
+#[test]
+fn check_platform_fee_update() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(PLATFORM), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Along with the 10% mint fee, the seller would be left with less than nothing
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids.clone(),
+        Some(update_platform_fee(Some(9_500), None)),
+    );
+    assert_eq!(
+        res[0].as_ref().unwrap_err().root_cause().to_string(),
+        "InvalidPlatformFeeBps"
+    );
+
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids.clone(),
+        Some(update_platform_fee(Some(0), None)),
+    );
+    assert!(res[0].is_ok());
+    let res = router
+        .execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(attribute(&res, "platform_fee"), None);
+
+    let res = sudo_update_params(
+        &mut router,
+        &vt.collection_response_vec,
+        vt.code_ids,
+        Some(update_platform_fee(Some(200), Some(PLATFORM.to_string()))),
+    );
+    assert!(res[0].is_ok());
+    let res = router
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    assert_eq!(
+        attribute(&res, "platform_fee"),
+        Some(format!("2000000{}", NATIVE_DENOM))
+    );
+}
@@ -0,0 +1,131 @@
+use cosmwasm_std::{coin, coins, Addr, Coin, Timestamp, Uint128};
+use cw4::Member;
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_splits::msg::{ExecuteMsg as SplitsExecuteMsg, Group};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::contract_boxes::contract_splits;
+use crate::common_setup::setup_accounts_and_block::{
+    instantiate_group, setup_block_time, CREATION_FEE,
+};
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::setup_open_edition_minter;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const MEMBER1: &str = "member0001";
+const MEMBER2: &str = "member0002";
+
+#[test]
+fn check_proceeds_contract() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg.clone()).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+
+    let group_addr = instantiate_group(
+        &mut router,
+        vec![
+            Member {
+                addr: MEMBER1.to_string(),
+                weight: 75,
+            },
+            Member {
+                addr: MEMBER2.to_string(),
+                weight: 25,
+            },
+        ],
+    );
+    let splits_id = router.store_code(contract_splits());
+    let splits_addr = router
+        .instantiate_contract(
+            splits_id,
+            creator.clone(),
+            &sg_splits::msg::InstantiateMsg {
+                group: Group::Cw4Address(group_addr.to_string()),
+                admin: None,
+            },
+            &[],
+            "splits",
+            None,
+        )
+        .unwrap();
+
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+
+    // Proceeds can't go to a plain account
+    let mut invalid_init_msg = init_msg.clone();
+    invalid_init_msg.proceeds_contract = Some(buyer.to_string());
+    let res = setup_open_edition_minter(&mut router, creator.clone(), invalid_init_msg);
+    assert_eq!(
+        res.error.unwrap().root_cause().to_string(),
+        "Proceeds contract must be an instantiated contract"
+    );
+
+    let mut init_msg = init_msg;
+    init_msg.proceeds_contract = Some(splits_addr.to_string());
+    let minter_addr = setup_open_edition_minter(&mut router, creator.clone(), init_msg)
+        .minter
+        .unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let creator_balance_before = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // The seller amount lands in the splits contract, net of the 10% network fee
+    let creator_balance_after = router.wrap().query_balance(&creator, NATIVE_DENOM).unwrap();
+    assert_eq!(creator_balance_before, creator_balance_after);
+    let balance = router
+        .wrap()
+        .query_balance(&splits_addr, NATIVE_DENOM)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(90_000_000));
+
+    let res = router.execute_contract(
+        Addr::unchecked(MEMBER1),
+        splits_addr,
+        &SplitsExecuteMsg::Distribute {},
+        &[],
+    );
+    assert!(res.is_ok());
+    let balance = router.wrap().query_balance(MEMBER1, NATIVE_DENOM).unwrap();
+    assert_eq!(balance.amount, Uint128::new(67_500_000));
+}
@@ -0,0 +1,108 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, MintCountResponse, QueryMsg, TotalMintCountResponse};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_purge() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(end_time),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    for minter in [&buyer, &creator] {
+        let res = router.execute_contract(
+            minter.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    let mint_count = |router: &App, address: String| {
+        let res: MintCountResponse = router
+            .wrap()
+            .query_wasm_smart(minter_addr.clone(), &QueryMsg::MintCount { address })
+            .unwrap();
+        res.count
+    };
+
+    // Nothing is purged while the mint is live
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Purge {},
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "MintingHasNotYetEnded: purge is only possible after the end time plus the purge delay"
+    );
+    assert_eq!(mint_count(&router, buyer.to_string()), 1);
+    assert_eq!(mint_count(&router, creator.to_string()), 1);
+
+    setup_block_time(&mut router, end_time.nanos() + 1, None);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Purge {},
+        &[],
+    );
+    assert!(res.is_ok());
+    assert_eq!(mint_count(&router, buyer.to_string()), 0);
+    assert_eq!(mint_count(&router, creator.to_string()), 0);
+
+    // Purging again is a no-op
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Purge {},
+        &[],
+    );
+    assert!(res.is_ok());
+    assert_eq!(mint_count(&router, buyer.to_string()), 0);
+
+    // Only the per address counts are freed
+    let res: TotalMintCountResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TotalMintCount {})
+        .unwrap();
+    assert_eq!(res.count, 2);
+}
@@ -0,0 +1,88 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+const PURGE_DELAY_SECS: u64 = 60;
+
+#[test]
+fn check_purge_delay() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(end_time),
+        None,
+        None,
+    );
+    init_msg.purge_delay_secs = Some(PURGE_DELAY_SECS);
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.purge_delay_secs, PURGE_DELAY_SECS);
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Anyone has to wait for the purge delay after the end time
+    setup_block_time(&mut router, end_time.nanos() + 1, None);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Purge {},
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "MintingHasNotYetEnded: purge is only possible after the end time plus the purge delay"
+    );
+
+    // The admin can purge right after the end time
+    let res = router.execute_contract(creator, minter_addr.clone(), &ExecuteMsg::Purge {}, &[]);
+    assert!(res.is_ok());
+
+    setup_block_time(
+        &mut router,
+        end_time.plus_seconds(PURGE_DELAY_SECS).nanos() + 1,
+        None,
+    );
+    let res = router.execute_contract(buyer, minter_addr, &ExecuteMsg::Purge {}, &[]);
+    assert!(res.is_ok());
+}
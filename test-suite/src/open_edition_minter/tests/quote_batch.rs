@@ -0,0 +1,121 @@
+use cosmwasm_std::{coin, coins, Addr, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, QuoteBatchResponse};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 200_000_000;
+const ALLOWLIST_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_quote_batch() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 1_000);
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        Some(start_time),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        Some(coin(MINT_PRICE, NATIVE_DENOM)),
+    );
+    init_msg.allowlist_mint_price = Some(coin(ALLOWLIST_PRICE, NATIVE_DENOM));
+    init_msg.per_tx_limit = Some(3);
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::SetAllowlist {
+            entries: vec![(buyer.to_string(), 2)],
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let quote = |router: &App, count: u32, address: Option<&Addr>| {
+        router.wrap().query_wasm_smart::<QuoteBatchResponse>(
+            minter_addr.clone(),
+            &QueryMsg::QuoteBatch {
+                count,
+                address: address.map(|address| address.to_string()),
+            },
+        )
+    };
+
+    // Before the start time only a single allowlist mint can be quoted
+    let res = quote(&router, 1, Some(&buyer)).unwrap();
+    assert_eq!(res.per_token, vec![coin(ALLOWLIST_PRICE, NATIVE_DENOM)]);
+    assert_eq!(res.total, coin(ALLOWLIST_PRICE, NATIVE_DENOM));
+    let res = quote(&router, 2, Some(&buyer));
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Allowlist mints are quoted one token at a time"));
+    let res = quote(&router, 1, None);
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Minting has not started yet"));
+
+    // Public batches are at the public price
+    setup_block_time(&mut router, start_time.nanos(), None);
+    let res = quote(&router, 2, Some(&buyer)).unwrap();
+    assert_eq!(
+        res.per_token,
+        vec![
+            coin(MINT_PRICE, NATIVE_DENOM),
+            coin(MINT_PRICE, NATIVE_DENOM)
+        ]
+    );
+    assert_eq!(res.total, coin(MINT_PRICE * 2, NATIVE_DENOM));
+
+    // Quotes are bounded like MintBatch
+    let res = quote(&router, 4, None);
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Per transaction mint limit exceeded, max: 3"));
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintBatch {
+            count: 3,
+            best_effort: None,
+        },
+        &coins(MINT_PRICE * 3, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+    let res = quote(&router, 3, Some(&buyer));
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Max minting limit per address exceeded"));
+    assert!(quote(&router, 3, None).is_ok());
+}
@@ -0,0 +1,219 @@
+use cosmwasm_std::{coin, coins, to_json_binary, Coin, Timestamp, Uint128};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{CanMintResponse, ExecuteMsg, QueryMsg, ReceiveMsg};
+
+use crate::common_setup::contract_boxes::{contract_cw20, App};
+use crate::common_setup::setup_accounts_and_block::{setup_block_time, CREATION_FEE};
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::setup_minter::open_edition_minter::setup::setup_open_edition_minter;
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const WINDOW_SECS: u64 = 3_600;
+const CW20_MINT_PRICE: u128 = 50_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_rate_limit_windows() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(4),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME).plus_seconds(100_000)),
+        None,
+        None,
+    );
+    init_msg.rate_limit = Some((2, WINDOW_SECS));
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let start = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 101);
+
+    let mint_at = |router: &mut App, secs: u64, msg: &ExecuteMsg, count: u128| {
+        setup_block_time(router, start.plus_seconds(secs).nanos(), None);
+        router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            msg,
+            &coins(MIN_MINT_PRICE_OPEN_EDITION * count, NATIVE_DENOM),
+        )
+    };
+
+    // 2 mints fill the window
+    assert!(mint_at(&mut router, 0, &ExecuteMsg::Mint {}, 1).is_ok());
+    assert!(mint_at(&mut router, 10, &ExecuteMsg::Mint {}, 1).is_ok());
+    let res = mint_at(&mut router, 20, &ExecuteMsg::Mint {}, 1);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Rate limited, retry after 3580 seconds"
+    );
+    let res: CanMintResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::CanMint {
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.reason,
+        Some("Rate limited, retry after 3580 seconds".to_string())
+    );
+
+    // The first mint leaves the window exactly one window later
+    let res = mint_at(&mut router, WINDOW_SECS - 1, &ExecuteMsg::Mint {}, 1);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Rate limited, retry after 1 seconds"
+    );
+    assert!(mint_at(&mut router, WINDOW_SECS, &ExecuteMsg::Mint {}, 1).is_ok());
+    let res = mint_at(&mut router, WINDOW_SECS, &ExecuteMsg::Mint {}, 1);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Rate limited, retry after 10 seconds"
+    );
+
+    // Batch mints count every token against the window
+    let batch = ExecuteMsg::MintBatch {
+        count: 2,
+        best_effort: None,
+    };
+    let res = mint_at(&mut router, WINDOW_SECS + 10, &batch, 2);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        format!("Rate limited, retry after {} seconds", WINDOW_SECS - 10)
+    );
+    let batch = ExecuteMsg::MintBatch {
+        count: 1,
+        best_effort: None,
+    };
+    assert!(mint_at(&mut router, WINDOW_SECS + 10, &batch, 1).is_ok());
+
+    // The lifetime per address limit still applies once the window is clear
+    let res = mint_at(&mut router, 3 * WINDOW_SECS, &ExecuteMsg::Mint {}, 1);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+}
+
+#[test]
+fn check_invalid_rate_limit() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.rate_limit = Some((0, WINDOW_SECS));
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let err = vt.collection_response_vec[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Rate limit needs at least one mint within a window of at least one second"
+    );
+}
+
+#[test]
+fn check_cw20_mint_rate_limit() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(4),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME).plus_seconds(100_000)),
+        None,
+        None,
+    );
+    init_msg.rate_limit = Some((1, WINDOW_SECS));
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg.clone()).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+
+    let cw20_id = router.store_code(contract_cw20());
+    let cw20_addr = router
+        .instantiate_contract(
+            cw20_id,
+            creator.clone(),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: buyer.to_string(),
+                    amount: Uint128::new(CW20_MINT_PRICE * 10),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20",
+            None,
+        )
+        .unwrap();
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+
+    let mut init_msg = init_msg;
+    init_msg.cw20_mint_price = Some(Cw20Coin {
+        address: cw20_addr.to_string(),
+        amount: Uint128::new(CW20_MINT_PRICE),
+    });
+    let minter_addr = setup_open_edition_minter(&mut router, creator, init_msg)
+        .minter
+        .unwrap();
+    let start = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 101);
+
+    let cw20_mint_at = |router: &mut App, secs: u64| {
+        setup_block_time(router, start.plus_seconds(secs).nanos(), None);
+        router.execute_contract(
+            buyer.clone(),
+            cw20_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: minter_addr.to_string(),
+                amount: Uint128::new(CW20_MINT_PRICE),
+                msg: to_json_binary(&ReceiveMsg::Mint {}).unwrap(),
+            },
+            &[],
+        )
+    };
+
+    // cw20 mints fill the window like native ones
+    assert!(cw20_mint_at(&mut router, 0).is_ok());
+    let res = cw20_mint_at(&mut router, 10);
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Rate limited, retry after 3590 seconds"
+    );
+    assert!(cw20_mint_at(&mut router, WINDOW_SECS).is_ok());
+}
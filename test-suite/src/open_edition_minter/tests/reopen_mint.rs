@@ -0,0 +1,126 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, StartTimeResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_reopen_mint() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(2),
+        None,
+        Some(end_time),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let new_end_time = end_time.plus_seconds(60);
+
+    // Can't reopen a mint that is still running
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ReopenMint { new_end_time },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Mint has not ended, use UpdateEndTime instead"
+    );
+
+    setup_block_time(&mut router, end_time.nanos(), None);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Minting has ended"
+    );
+
+    // Only the admin can reopen
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ReopenMint { new_end_time },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    // The new end time has to be in the future
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::ReopenMint {
+            new_end_time: end_time,
+        },
+        &[],
+    );
+    assert!(res.is_err());
+
+    let res = router
+        .execute_contract(
+            creator,
+            minter_addr.clone(),
+            &ExecuteMsg::ReopenMint { new_end_time },
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "reopened" && attr.value == end_time.to_string())));
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Start time is left untouched
+    let res: StartTimeResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::StartTime {})
+        .unwrap();
+    assert_eq!(
+        res.start_time,
+        Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100).to_string()
+    );
+}
@@ -0,0 +1,114 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::contract_boxes::App;
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const AIRDROP_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(AIRDROP_PRICE),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_public_mint_waits_for_reserve() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.reserved_tokens = Some(2);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let query_reserve = |router: &App| {
+        let res: ConfigResponse = router
+            .wrap()
+            .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        (res.reserved_tokens, res.reserved_minted)
+    };
+    assert_eq!(query_reserve(&router), (2, 0));
+
+    for reserved_minted in 1..=2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+        );
+        assert_eq!(
+            res.err().unwrap().source().unwrap().to_string(),
+            "The team reserve has not been minted yet"
+        );
+
+        let res = router.execute_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::MintTo {
+                recipient: creator.to_string(),
+            },
+            &coins(AIRDROP_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+        assert_eq!(query_reserve(&router), (2, reserved_minted));
+    }
+
+    // The reserve is out, the public mint opens
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn check_reserve_larger_than_supply() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(2),
+        None,
+    );
+    init_msg.reserved_tokens = Some(3);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let err = vt.collection_response_vec[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Reserved tokens can't exceed the number of tokens or the airdrop limit"
+    );
+}
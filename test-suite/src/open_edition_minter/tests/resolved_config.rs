@@ -0,0 +1,64 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{MigrateMsg, QueryMsg, ResolvedConfigResponse};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_resolved_config_after_migration() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10_000,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let code_id = router
+        .wrap()
+        .query_wasm_contract_info(minter_addr.clone())
+        .unwrap()
+        .code_id;
+    let res = router.migrate_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &MigrateMsg::Noop {},
+        code_id,
+    );
+    assert!(res.is_ok());
+
+    // Optional fields left unset are reported with their defaults
+    let res: ResolvedConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::ResolvedConfig {})
+        .unwrap();
+    assert_eq!(res.payment_address, creator);
+    assert_eq!(res.lifetime_per_address_cap, u32::MAX);
+    assert_eq!(res.unique_minter_count, 0);
+}
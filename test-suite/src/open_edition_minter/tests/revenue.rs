@@ -0,0 +1,75 @@
+use cosmwasm_std::{coin, coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, RevenueResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::{DEV_ADDRESS, MINT_FEE_FAIR_BURN};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_revenue_tracking() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10_000,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    // Nothing is reported before the first sale
+    let res: RevenueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Revenue {})
+        .unwrap();
+    assert!(res.seller_amount.is_empty());
+    assert!(res.network_fees.is_empty());
+
+    for _ in 1..=2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    let network_fee = 2 * MINT_PRICE * MINT_FEE_FAIR_BURN as u128 / 10_000;
+    let res: RevenueResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Revenue {})
+        .unwrap();
+    assert_eq!(
+        res.seller_amount,
+        vec![coin(2 * MINT_PRICE - network_fee, NATIVE_DENOM)]
+    );
+    assert_eq!(res.network_fees, vec![coin(network_fee, NATIVE_DENOM)]);
+}
@@ -0,0 +1,60 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{CollectionResponse, QueryMsg, SudoMsg};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_set_sg721_address_only_when_unset() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+
+    // The reply already wired the collection, it can't be overwritten
+    let res = router.wasm_sudo(
+        minter_addr.clone(),
+        &SudoMsg::SetSg721Address {
+            address: "other_collection".to_string(),
+        },
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Sg721 address is already set"
+    );
+
+    let res: CollectionResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Collection {})
+        .unwrap();
+    assert_eq!(res.address, collection_addr.to_string());
+}
@@ -0,0 +1,104 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::{NftData, NftMetadataType};
+use sg_metadata::Metadata;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{QueryMsg, ShuffleSeedResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::init_msg;
+use crate::common_setup::templates::open_edition_minter_nft_data;
+
+const SHUFFLE_SEED: u64 = 42;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn extension_pool(len: u32) -> Vec<Metadata> {
+    (1..=len)
+        .map(|i| Metadata {
+            image: Some(format!("https://example.com/{i}.png")),
+            ..Metadata::default()
+        })
+        .collect()
+}
+
+fn on_chain_nft_data() -> NftData {
+    NftData {
+        nft_data_type: NftMetadataType::OnChainMetadata,
+        extension: Some(Metadata {
+            image: Some("https://example.com/base.png".to_string()),
+            ..Metadata::default()
+        }),
+        token_uri: None,
+    }
+}
+
+#[test]
+fn check_shuffle_seed_disclosed_after_end() {
+    let nft_data = on_chain_nft_data();
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let mut init_msg = init_msg(nft_data.clone(), None, None, Some(end_time), Some(5), None);
+    init_msg.extension_pool = Some(extension_pool(5));
+    init_msg.shuffle_seed = Some(SHUFFLE_SEED);
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg, nft_data).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // Hidden while minting is open
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+    let res: ShuffleSeedResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::ShuffleSeed {})
+        .unwrap();
+    assert_eq!(res.shuffle_seed, None);
+
+    setup_block_time(&mut router, end_time.nanos(), None);
+    let res: ShuffleSeedResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::ShuffleSeed {})
+        .unwrap();
+    assert_eq!(res.shuffle_seed, Some(SHUFFLE_SEED));
+}
+
+#[test]
+fn check_shuffle_seed_requires_pool_per_token() {
+    let nft_data = on_chain_nft_data();
+    let mut init_msg = init_msg(
+        nft_data.clone(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(5),
+        None,
+    );
+    init_msg.extension_pool = Some(extension_pool(4));
+    init_msg.shuffle_seed = Some(SHUFFLE_SEED);
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg, nft_data).unwrap();
+    assert_eq!(
+        vt.collection_response_vec[0]
+            .error
+            .as_ref()
+            .unwrap()
+            .root_cause()
+            .to_string(),
+        "ExtensionPoolTooSmall"
+    );
+}
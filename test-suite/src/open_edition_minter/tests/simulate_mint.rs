@@ -0,0 +1,159 @@
+use cosmwasm_std::{coin, coins, Coin, Decimal, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg, SimulateMintResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_simulate_mint() {
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    // Public mints pay the 10% network fee
+    let res: SimulateMintResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::SimulateMint {
+                sender: buyer.to_string(),
+                is_admin: false,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        SimulateMintResponse {
+            mint_price: coin(MINT_PRICE, NATIVE_DENOM),
+            network_fee: coin(10_000_000, NATIVE_DENOM),
+            seller_amount: coin(90_000_000, NATIVE_DENOM),
+            payouts: vec![(config.admin.clone(), coin(90_000_000, NATIVE_DENOM))],
+        }
+    );
+
+    // Admin mints pay the airdrop price and fee
+    let res: SimulateMintResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::SimulateMint {
+                sender: creator.to_string(),
+                is_admin: true,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.network_fee, coin(1_000_000, NATIVE_DENOM));
+    assert_eq!(res.seller_amount, coin(99_000_000, NATIVE_DENOM));
+
+    let res: Result<SimulateMintResponse, _> = router.wrap().query_wasm_smart(
+        minter_addr.clone(),
+        &QueryMsg::SimulateMint {
+            sender: buyer.to_string(),
+            is_admin: true,
+        },
+    );
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("Sender is not an admin"));
+
+    // The simulation matches an executed mint
+    let res = router
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    let seller_amount = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "seller_amount")
+        .unwrap()
+        .value
+        .clone();
+    assert_eq!(seller_amount, format!("90000000{}", NATIVE_DENOM));
+}
+
+#[test]
+fn check_simulate_mint_payment_splits() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.payment_splits = Some(vec![
+        ("artist1".to_string(), Decimal::percent(70)),
+        ("artist2".to_string(), Decimal::percent(30)),
+    ]);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // The seller amount is reported as the splits pay it
+    let res: SimulateMintResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::SimulateMint {
+                sender: buyer.to_string(),
+                is_admin: false,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.seller_amount, coin(90_000_000, NATIVE_DENOM));
+    assert_eq!(
+        res.payouts,
+        vec![
+            ("artist1".to_string(), coin(63_000_000, NATIVE_DENOM)),
+            ("artist2".to_string(), coin(27_000_000, NATIVE_DENOM)),
+        ]
+    );
+}
@@ -0,0 +1,112 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg4::MinterConfig;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, MigrateMsg, QueryMsg};
+use open_edition_minter::state::CONFIG;
+use open_edition_minter::upgrades::v3_6_0::{LegacyConfigExtension, LEGACY_CONFIG};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_migrate_legacy_config() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        Some(10),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let code_id = router
+        .wrap()
+        .query_wasm_contract_info(minter_addr.clone())
+        .unwrap()
+        .code_id;
+
+    // Rewrite the state as a 3.5.0 minter stored it
+    {
+        let mut storage = router.contract_storage_mut(&minter_addr);
+        let config = CONFIG.load(storage.as_ref()).unwrap();
+        let legacy = MinterConfig {
+            factory: config.factory,
+            collection_code_id: config.collection_code_id,
+            mint_price: config.mint_price,
+            extension: LegacyConfigExtension {
+                admin: config.extension.admin,
+                payment_address: config.extension.payment_address,
+                nft_data: config.extension.nft_data,
+                start_time: config.extension.start_time,
+                end_time: config.extension.end_time,
+                per_address_limit: config.extension.per_address_limit,
+                num_tokens: config.extension.num_tokens,
+            },
+        };
+        LEGACY_CONFIG.save(storage.as_mut(), &legacy).unwrap();
+        cw2::set_contract_version(
+            storage.as_mut(),
+            "crates.io:sg-open-edition-minter",
+            "3.5.0",
+        )
+        .unwrap();
+    }
+
+    // The legacy layout can't be read by the current code
+    let res: Result<ConfigResponse, _> = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {});
+    assert!(res.is_err());
+
+    let res = router
+        .migrate_contract(
+            creator.clone(),
+            minter_addr.clone(),
+            &MigrateMsg::Noop {},
+            code_id,
+        )
+        .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-migrate-3.6.0"));
+
+    // Existing values are kept and new fields get their defaults
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.admin, creator.to_string());
+    assert_eq!(res.per_address_limit, 5);
+    assert_eq!(res.num_tokens, Some(10));
+    assert_eq!(res.lifetime_per_address_cap, None);
+    assert_eq!(res.referral_bps, 0);
+    assert_eq!(res.purge_delay_secs, 0);
+    assert!(res.extension_pool.is_empty());
+
+    let version = cw2::query_contract_info(&router.wrap(), minter_addr).unwrap();
+    assert_eq!(version.version, "3.6.0");
+}
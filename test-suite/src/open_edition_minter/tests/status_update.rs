@@ -0,0 +1,168 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg4::StatusResponse;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, SudoMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_update_status_event() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let update_status = |is_blocked: bool| SudoMsg::UpdateStatus {
+        is_verified: true,
+        is_blocked,
+        is_explicit: false,
+    };
+    let status_attr = |res: &cw_multi_test::AppResponse, key: &str| {
+        res.events
+            .iter()
+            .find(|event| event.ty == "wasm-status-update")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .unwrap()
+            .value
+            .clone()
+    };
+
+    // Block the minter
+    let res = router
+        .wasm_sudo(minter_addr.clone(), &update_status(true))
+        .unwrap();
+    assert_eq!(status_attr(&res, "is_verified"), "true");
+    assert_eq!(status_attr(&res, "is_blocked"), "true");
+    assert_eq!(status_attr(&res, "is_explicit"), "false");
+    assert_eq!(status_attr(&res, "was_blocked"), "false");
+
+    let res: StatusResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Status {})
+        .unwrap();
+    assert!(res.status.is_verified);
+    assert!(res.status.is_blocked);
+
+    // Unblocking reports the previous blocked state
+    let res = router
+        .wasm_sudo(minter_addr.clone(), &update_status(false))
+        .unwrap();
+    assert_eq!(status_attr(&res, "is_blocked"), "false");
+    assert_eq!(status_attr(&res, "was_blocked"), "true");
+
+    let res: StatusResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::Status {})
+        .unwrap();
+    assert!(!res.status.is_blocked);
+}
+
+#[test]
+fn check_blocked_minter_rejects_mints() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let update_status = |is_blocked: bool| SudoMsg::UpdateStatus {
+        is_verified: false,
+        is_blocked,
+        is_explicit: false,
+    };
+    router
+        .wasm_sudo(minter_addr.clone(), &update_status(true))
+        .unwrap();
+
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Minter is blocked"
+    );
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::MintTo {
+            recipient: buyer.to_string(),
+        },
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Minter is blocked"
+    );
+
+    // Unblocked minters mint again
+    router
+        .wasm_sudo(minter_addr.clone(), &update_status(false))
+        .unwrap();
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+}
@@ -0,0 +1,85 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{QueryMsg, TimeRemainingResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_time_remaining() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let genesis = Timestamp::from_nanos(GENESIS_MINT_START_TIME);
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        Some(genesis.plus_seconds(1_000)),
+        Some(genesis.plus_seconds(5_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let mut router = vt.router;
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    // Before the start time
+    setup_block_time(&mut router, genesis.plus_seconds(400).nanos(), None);
+    let res: TimeRemainingResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TimeRemaining {})
+        .unwrap();
+    assert_eq!(
+        res,
+        TimeRemainingResponse {
+            seconds_until_start: 600,
+            seconds_until_end: Some(4_600),
+        }
+    );
+
+    // During the mint
+    setup_block_time(&mut router, genesis.plus_seconds(2_000).nanos(), None);
+    let res: TimeRemainingResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::TimeRemaining {})
+        .unwrap();
+    assert_eq!(
+        res,
+        TimeRemainingResponse {
+            seconds_until_start: 0,
+            seconds_until_end: Some(3_000),
+        }
+    );
+
+    // After the end time both saturate at zero
+    setup_block_time(&mut router, genesis.plus_seconds(10_000).nanos(), None);
+    let res: TimeRemainingResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr, &QueryMsg::TimeRemaining {})
+        .unwrap();
+    assert_eq!(
+        res,
+        TimeRemainingResponse {
+            seconds_until_start: 0,
+            seconds_until_end: Some(0),
+        }
+    );
+}
@@ -0,0 +1,100 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_token_id_prefix() {
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.token_id_prefix = Some("oem".to_string());
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.token_id_prefix, Some("oem".to_string()));
+
+    let res = router
+        .execute_contract(
+            buyer,
+            minter_addr,
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        )
+        .unwrap();
+    let token_id = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "token_id")
+        .unwrap()
+        .value
+        .clone();
+    assert_eq!(token_id, "oem-1");
+}
+
+#[test]
+fn check_invalid_token_id_prefix() {
+    let too_long = "a".repeat(33);
+    for prefix in ["", "oem 1", "oem/1", too_long.as_str()] {
+        let mut init_msg = init_msg(
+            default_nft_data(),
+            Some(5),
+            None,
+            Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+            None,
+            None,
+        );
+        init_msg.token_id_prefix = Some(prefix.to_string());
+        let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+        assert_eq!(
+            vt.collection_response_vec[0]
+                .error
+                .as_ref()
+                .unwrap()
+                .root_cause()
+                .to_string(),
+            "Token id prefix must be 1 to 32 letters, digits or dashes"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use cosmwasm_std::{
+    coin, coins, to_json_binary, Binary, Coin, Deps, DepsMut, Empty, Env, HexBinary, MessageInfo,
+    Response, StdResult, Timestamp, Uint128,
+};
+use cw_multi_test::{BankSudo, ContractWrapper, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::{NftData, NftMetadataType};
+use sg_metadata::Metadata;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::helpers::fallback_token_seed;
+use open_edition_minter::msg::{
+    ExecuteMsg, QueryMsg, RandomSeedResponse, RandomnessProviderQueryMsg, TokenSeedResponse,
+};
+
+use crate::common_setup::setup_accounts_and_block::{setup_block_time, CREATION_FEE};
+use crate::common_setup::setup_minter::common::constants::{
+    DEV_ADDRESS, MIN_MINT_PRICE_OPEN_EDITION,
+};
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::init_msg;
+use crate::common_setup::setup_minter::open_edition_minter::setup::setup_open_edition_minter;
+use crate::common_setup::templates::open_edition_minter_nft_data;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+fn on_chain_nft_data() -> NftData {
+    NftData {
+        nft_data_type: NftMetadataType::OnChainMetadata,
+        extension: Some(Metadata {
+            image: Some("https://example.com/base.png".to_string()),
+            name: Some("Base".to_string()),
+            ..Metadata::default()
+        }),
+        token_uri: None,
+    }
+}
+
+fn provider_seed(token_id: &str) -> HexBinary {
+    HexBinary::from(format!("seed-{token_id}").as_bytes())
+}
+
+fn provider_query(_deps: Deps, _env: Env, msg: RandomnessProviderQueryMsg) -> StdResult<Binary> {
+    match msg {
+        RandomnessProviderQueryMsg::RandomSeed { token_id } => {
+            to_json_binary(&RandomSeedResponse {
+                seed: provider_seed(&token_id),
+            })
+        }
+    }
+}
+
+fn provider_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+#[test]
+fn check_fallback_token_seed() {
+    let nft_data = on_chain_nft_data();
+    let init_msg = init_msg(
+        nft_data.clone(),
+        Some(3),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg, nft_data).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res: TokenSeedResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::TokenSeed {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.seed, None);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // No provider, the seed is derived from the block time and token id
+    let expected = fallback_token_seed(router.block_info().time, "1");
+    let res: TokenSeedResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::TokenSeed {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.seed, Some(expected));
+}
+
+#[test]
+fn check_randomness_provider_seed() {
+    let nft_data = on_chain_nft_data();
+    let init_msg = init_msg(
+        nft_data.clone(),
+        Some(3),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_nft_data(params_extension(), init_msg.clone(), nft_data).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+
+    let provider_id = router.store_code(Box::new(ContractWrapper::new_with_empty(
+        provider_execute,
+        provider_execute,
+        provider_query,
+    )));
+    let provider_addr = router
+        .instantiate_contract(
+            provider_id,
+            creator.clone(),
+            &Empty {},
+            &[],
+            "provider",
+            None,
+        )
+        .unwrap();
+
+    router
+        .sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: creator.to_string(),
+            amount: vec![coin(CREATION_FEE, NATIVE_DENOM)],
+        }))
+        .unwrap();
+    let mut init_msg = init_msg;
+    init_msg.randomness_provider = Some(provider_addr.to_string());
+    let minter_addr = setup_open_edition_minter(&mut router, creator, init_msg)
+        .minter
+        .unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MIN_MINT_PRICE_OPEN_EDITION, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: TokenSeedResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::TokenSeed {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.seed, Some(provider_seed("1")));
+}
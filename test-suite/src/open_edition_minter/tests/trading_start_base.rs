@@ -0,0 +1,99 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use open_edition_factory::state::ParamsExtension;
+use open_edition_factory::types::TradingStartBase;
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MAX_TRADING_OFFSET_SECS: u64 = 60 * 60 * 24 * 7;
+
+fn params_extension() -> ParamsExtension {
+    ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    }
+}
+
+#[test]
+fn check_start_based_trading_time() {
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100);
+    let mut init_msg = init_msg(
+        default_nft_data(),
+        None,
+        Some(start_time),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    init_msg.trading_start_base = Some(TradingStartBase::StartBased);
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+
+    let res: CollectionInfoResponse = vt
+        .router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+    assert_eq!(
+        res.start_trading_time,
+        Some(start_time.plus_seconds(MAX_TRADING_OFFSET_SECS))
+    );
+}
+
+#[test]
+fn check_end_based_trading_time() {
+    let end_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000);
+    let mut init_msg = init_msg(default_nft_data(), None, None, Some(end_time), None, None);
+    init_msg.trading_start_base = Some(TradingStartBase::EndBased { offset_secs: 60 });
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg.clone()).unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+
+    let res: CollectionInfoResponse = vt
+        .router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+    assert_eq!(res.start_trading_time, Some(end_time.plus_seconds(60)));
+
+    // The offset from the end time can't exceed the factory's max trading offset
+    init_msg.trading_start_base = Some(TradingStartBase::EndBased {
+        offset_secs: MAX_TRADING_OFFSET_SECS + 1,
+    });
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg.clone()).unwrap();
+    let err = vt.collection_response_vec[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!(
+            "InvalidStartTradingTime {} > {}",
+            end_time.plus_seconds(MAX_TRADING_OFFSET_SECS + 1),
+            end_time.plus_seconds(MAX_TRADING_OFFSET_SECS)
+        )
+    );
+
+    // An end based trading time needs an end time
+    init_msg.end_time = None;
+    init_msg.num_tokens = Some(5);
+    init_msg.trading_start_base = Some(TradingStartBase::EndBased { offset_secs: 0 });
+    let vt = open_edition_minter_custom_template(params_extension(), init_msg).unwrap();
+    let err = vt.collection_response_vec[0].error.as_ref().unwrap();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Start trading time can only be based on the end time if the mint has one"
+    );
+}
@@ -0,0 +1,146 @@
+use cosmwasm_std::{coins, Addr, Coin, Timestamp, Uint128};
+use cw_multi_test::{BankSudo, Executor, SudoMsg};
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, UniqueMinterCurveResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_unique_minter_curve() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10_000,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+
+    let other_buyers = vec![Addr::unchecked("buyer2"), Addr::unchecked("buyer3")];
+    for other_buyer in other_buyers.iter() {
+        router
+            .sudo(SudoMsg::Bank({
+                BankSudo::Mint {
+                    to_address: other_buyer.to_string(),
+                    amount: coins(MINT_PRICE * 2, NATIVE_DENOM),
+                }
+            }))
+            .unwrap();
+    }
+
+    // Empty curve before any mint
+    let res: UniqueMinterCurveResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::UniqueMinterCurve {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.points.is_empty());
+
+    // Block 100: buyer mints twice -> only one unique minter
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, Some(100));
+    for _ in 1..=2 {
+        let res = router.execute_contract(
+            buyer.clone(),
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    // Block 200: a new minter and a returning one
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 200, Some(200));
+    for minter in [other_buyers[0].clone(), buyer.clone()] {
+        let res = router.execute_contract(
+            minter,
+            minter_addr.clone(),
+            &ExecuteMsg::Mint {},
+            &coins(MINT_PRICE, NATIVE_DENOM),
+        );
+        assert!(res.is_ok());
+    }
+
+    // Block 300: only a returning minter -> no new checkpoint
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 300, Some(300));
+    let res = router.execute_contract(
+        other_buyers[0].clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // Block 400: last new minter
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 400, Some(400));
+    let res = router.execute_contract(
+        other_buyers[1].clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    let res: UniqueMinterCurveResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::UniqueMinterCurve {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let points = res
+        .points
+        .iter()
+        .map(|p| (p.block_height, p.cumulative_unique))
+        .collect::<Vec<_>>();
+    assert_eq!(points, vec![(100, 1), (200, 2), (400, 3)]);
+
+    // Paginate from the first checkpoint
+    let res: UniqueMinterCurveResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::UniqueMinterCurve {
+                start_after: Some(100),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.points.len(), 1);
+    assert_eq!(res.points[0].block_height, 200);
+    assert_eq!(res.points[0].cumulative_unique, 2);
+}
@@ -0,0 +1,127 @@
+use cosmwasm_std::{coins, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::{ExecuteMsg, QueryMsg, UnlimitedAddressesResponse};
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const MINT_PRICE: u128 = 100_000_000;
+
+#[test]
+fn check_unlimited_addresses() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(1),
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 101, None);
+
+    let set_msg = ExecuteMsg::SetUnlimitedAddresses {
+        addresses: vec![buyer.to_string()],
+    };
+    let res = router.execute_contract(buyer.clone(), minter_addr.clone(), &set_msg, &[]);
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetUnlimitedAddresses {
+            addresses: vec!["".to_string()],
+        },
+        &[],
+    );
+    assert!(res.is_err());
+    let res = router.execute_contract(creator.clone(), minter_addr.clone(), &set_msg, &[]);
+    assert!(res.is_ok());
+
+    let res: UnlimitedAddressesResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::UnlimitedAddresses {})
+        .unwrap();
+    assert_eq!(res.addresses, vec![buyer.clone()]);
+
+    // The exempt buyer mints past the limit of 1, at the normal price
+    for _ in 0..3 {
+        let res = router
+            .execute_contract(
+                buyer.clone(),
+                minter_addr.clone(),
+                &ExecuteMsg::Mint {},
+                &coins(MINT_PRICE, NATIVE_DENOM),
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .any(|attr| attr.key == "limit_exempt" && attr.value == "true"));
+    }
+
+    // Everyone else is still capped
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+
+    // Clearing the list restores the limit
+    let res = router.execute_contract(
+        creator,
+        minter_addr.clone(),
+        &ExecuteMsg::SetUnlimitedAddresses { addresses: vec![] },
+        &[],
+    );
+    assert!(res.is_ok());
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::Mint {},
+        &coins(MINT_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+}
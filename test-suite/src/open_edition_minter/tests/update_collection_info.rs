@@ -0,0 +1,202 @@
+use cosmwasm_std::{Coin, Empty, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg721::{ExecuteMsg as Sg721ExecuteMsg, RoyaltyInfoResponse, UpdateCollectionInfoMsg};
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_update_collection_info() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+
+    let before: CollectionInfoResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr.clone(), &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+
+    let res = router.execute_contract(
+        buyer,
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateCollectionInfo {
+            description: Some("New description".to_string()),
+            image: None,
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateCollectionInfo {
+            description: Some("a".repeat(513)),
+            image: None,
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Description too long"
+    );
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateCollectionInfo {
+            description: None,
+            image: Some("not a url".to_string()),
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "relative URL without a base"
+    );
+
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::UpdateCollectionInfo {
+            description: Some("New description".to_string()),
+            image: Some("https://example.com/collection.png".to_string()),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    // Only the description and image changed
+    let after: CollectionInfoResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+    assert_eq!(after.description, "New description");
+    assert_eq!(after.image, "https://example.com/collection.png");
+    assert_eq!(after.creator, before.creator);
+    assert_eq!(after.external_link, before.external_link);
+    assert_eq!(after.explicit_content, before.explicit_content);
+    assert_eq!(after.royalty_info, before.royalty_info);
+}
+
+#[test]
+fn check_minter_only_forwards_display_fields() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, buyer) = (vt.router, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    let before: CollectionInfoResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr.clone(), &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+
+    let update_msg = |creator: Option<String>,
+                      royalty_info: Option<Option<RoyaltyInfoResponse>>,
+                      explicit_content: Option<bool>| {
+        Sg721ExecuteMsg::<Option<Empty>, Empty>::UpdateCollectionInfo {
+            collection_info: UpdateCollectionInfoMsg {
+                description: Some("New description".to_string()),
+                image: None,
+                external_link: None,
+                explicit_content,
+                royalty_info,
+                creator,
+            },
+        }
+    };
+
+    // The minter, as the collection owner, can't take over the creator's fields
+    for msg in [
+        update_msg(Some(buyer.to_string()), None, before.explicit_content),
+        update_msg(None, Some(None), before.explicit_content),
+        update_msg(
+            None,
+            None,
+            Some(!before.explicit_content.unwrap_or_default()),
+        ),
+    ] {
+        let res = router.execute_contract(minter_addr.clone(), collection_addr.clone(), &msg, &[]);
+        assert_eq!(
+            res.err().unwrap().source().unwrap().to_string(),
+            "Unauthorized"
+        );
+    }
+
+    // Neither can anyone else
+    let res = router.execute_contract(
+        buyer,
+        collection_addr.clone(),
+        &update_msg(None, None, before.explicit_content),
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized"
+    );
+
+    let res = router.execute_contract(
+        minter_addr,
+        collection_addr,
+        &update_msg(None, None, before.explicit_content),
+        &[],
+    );
+    assert!(res.is_ok());
+}
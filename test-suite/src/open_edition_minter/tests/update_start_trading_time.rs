@@ -0,0 +1,76 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+
+use open_edition_minter::msg::ExecuteMsg;
+
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+#[test]
+fn check_start_trading_time_frozen_once_trading_started() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        None,
+        None,
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000)),
+        None,
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator) = (vt.router, vt.accts.creator);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME, None);
+
+    let start_trading_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 1_000);
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::UpdateStartTradingTime(Some(start_trading_time)),
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: CollectionInfoResponse = router
+        .wrap()
+        .query_wasm_smart(collection_addr, &Sg721QueryMsg::CollectionInfo {})
+        .unwrap();
+    assert_eq!(res.start_trading_time, Some(start_trading_time));
+
+    // Trading has begun, the start can't be moved anymore
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 2_000, None);
+    let res = router.execute_contract(
+        creator,
+        minter_addr,
+        &ExecuteMsg::UpdateStartTradingTime(Some(Timestamp::from_nanos(
+            GENESIS_MINT_START_TIME + 5_000,
+        ))),
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        format!("Trading already started at {start_trading_time}")
+    );
+}
@@ -0,0 +1,204 @@
+use cosmwasm_std::{coin, coins, Addr, Coin, Timestamp, Uint128};
+use cw_multi_test::Executor;
+use open_edition_factory::state::ParamsExtension;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use sg_whitelist::msg::InstantiateMsg as WhitelistInstantiateMsg;
+
+use open_edition_minter::msg::{ConfigResponse, ExecuteMsg, QueryMsg};
+
+use crate::common_setup::contract_boxes::{contract_collection_whitelist, App};
+use crate::common_setup::setup_accounts_and_block::setup_block_time;
+use crate::common_setup::setup_collection_whitelist::setup_whitelist_contract;
+use crate::common_setup::setup_minter::common::constants::DEV_ADDRESS;
+use crate::common_setup::setup_minter::open_edition_minter::minter_params::{
+    default_nft_data, init_msg,
+};
+use crate::common_setup::templates::open_edition_minter_custom_template;
+
+const WHITELIST_PRICE: u128 = 150_000_000;
+
+// Active from GENESIS_MINT_START_TIME + 100, one mint per address
+fn setup_priced_whitelist(router: &mut App, creator: &Addr, members: Vec<String>) -> Addr {
+    let code_id = router.store_code(contract_collection_whitelist());
+    let msg = WhitelistInstantiateMsg {
+        members,
+        start_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + 100),
+        end_time: Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000_000),
+        mint_price: coin(WHITELIST_PRICE, NATIVE_DENOM),
+        per_address_limit: 1,
+        member_limit: 1000,
+        admins: vec![creator.to_string()],
+        admins_mutable: true,
+    };
+    router
+        .instantiate_contract(
+            code_id,
+            creator.clone(),
+            &msg,
+            &[coin(100_000_000, NATIVE_DENOM)],
+            "whitelist",
+            None,
+        )
+        .unwrap()
+}
+
+#[test]
+fn check_mint_whitelist() {
+    let params_extension = ParamsExtension {
+        max_token_limit: 10,
+        max_per_address_limit: 10,
+        airdrop_mint_fee_bps: 100,
+        airdrop_mint_price: Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(100_000_000u128),
+        },
+        dev_fee_address: DEV_ADDRESS.to_string(),
+        max_batch_mint_size: 50,
+        allowed_denoms: vec![],
+        platform_fee_bps: None,
+        platform_fee_address: None,
+        verified_mint_fee_bps: None,
+    };
+    let init_msg = init_msg(
+        default_nft_data(),
+        Some(5),
+        Some(Timestamp::from_nanos(GENESIS_MINT_START_TIME + 1_000_000)),
+        None,
+        Some(10),
+        None,
+    );
+    let vt = open_edition_minter_custom_template(params_extension, init_msg).unwrap();
+    let (mut router, creator, buyer) = (vt.router, vt.accts.creator, vt.accts.buyer);
+    let minter_addr = vt.collection_response_vec[0].minter.clone().unwrap();
+    let collection_addr = vt.collection_response_vec[0].collection.clone().unwrap();
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME, None);
+
+    // Nothing to mint against before a whitelist is set
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "address not on whitelist: buyer"
+    );
+
+    // Only a whitelist contract is accepted
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetWhitelist {
+            address: collection_addr.to_string(),
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Whitelist contract returned an unexpected response"
+    );
+
+    // The whitelist price can't go below the factory minimum
+    let cheap_whitelist = setup_whitelist_contract(&mut router, &creator, None, None);
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetWhitelist {
+            address: cheap_whitelist.to_string(),
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Minimum whitelist mint price 100000000 got 66000000"
+    );
+
+    let whitelist = setup_priced_whitelist(&mut router, &creator, vec![buyer.to_string()]);
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetWhitelist {
+            address: whitelist.to_string(),
+        },
+        &[],
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Unauthorized: Sender is not an admin"
+    );
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::SetWhitelist {
+            address: whitelist.to_string(),
+        },
+        &[],
+    );
+    assert!(res.is_ok());
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(minter_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.whitelist, Some(whitelist.to_string()));
+
+    // The whitelist hasn't started yet
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "WhitelistNotActive"
+    );
+
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 200, None);
+
+    // Members pay the whitelist price
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert!(res.is_ok());
+
+    // The whitelist's per address limit is enforced
+    let res = router.execute_contract(
+        buyer.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Max minting limit per address exceeded"
+    );
+
+    let res = router.execute_contract(
+        creator.clone(),
+        minter_addr.clone(),
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "address not on whitelist: creator"
+    );
+
+    // The public mint takes over at the start time
+    setup_block_time(&mut router, GENESIS_MINT_START_TIME + 1_000_000, None);
+    let res = router.execute_contract(
+        buyer,
+        minter_addr,
+        &ExecuteMsg::MintWhitelist {},
+        &coins(WHITELIST_PRICE, NATIVE_DENOM),
+    );
+    assert_eq!(
+        res.err().unwrap().source().unwrap().to_string(),
+        "Whitelist minting is only possible before the start time"
+    );
+}
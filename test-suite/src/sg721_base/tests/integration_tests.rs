@@ -154,6 +154,8 @@ mod tests {
                     explicit_content: None,
                     start_trading_time: None,
                     royalty_info: None,
+                    max_supply: None,
+                    enforce_royalties_on_transfer: None,
                 },
             };
             let res = app.instantiate_contract(
@@ -293,6 +295,126 @@ mod tests {
             assert!(res.is_err());
         }
 
+        #[test]
+        fn royalty_decrease_skips_cooldown() {
+            let params = mock_collection_params();
+            let custom_create_minter_msg =
+                mock_create_minter_init_msg(params, mock_init_extension(None, None));
+            let (mut app, contract) = custom_proper_instantiate(custom_create_minter_msg);
+            let creator = Addr::unchecked("creator".to_string());
+
+            let update_royalty_msg =
+                |share: u64| Sg721ExecuteMsg::<Empty, Empty>::UpdateCollectionInfo {
+                    collection_info: UpdateCollectionInfoMsg {
+                        creator: None,
+                        description: None,
+                        image: None,
+                        external_link: None,
+                        explicit_content: None,
+                        royalty_info: Some(Some(RoyaltyInfoResponse {
+                            payment_address: creator.to_string(),
+                            share: Decimal::percent(share),
+                        })),
+                    },
+                };
+
+            // decreasing twice in the same block succeeds
+            let res = app.execute_contract(
+                creator.clone(),
+                contract.clone(),
+                &update_royalty_msg(8),
+                &[],
+            );
+            assert!(res.is_ok());
+            let res = app.execute_contract(
+                creator.clone(),
+                contract.clone(),
+                &update_royalty_msg(5),
+                &[],
+            );
+            assert!(res.is_ok());
+
+            let res: CollectionInfoResponse = app
+                .wrap()
+                .query_wasm_smart(contract.clone(), &QueryMsg::CollectionInfo {})
+                .unwrap();
+            assert_eq!(res.royalty_info.unwrap().share, Decimal::percent(5));
+
+            // increasing is still rate limited
+            let res = app.execute_contract(
+                creator.clone(),
+                contract.clone(),
+                &update_royalty_msg(6),
+                &[],
+            );
+            assert_error(
+                res,
+                ContractError::InvalidRoyalties(
+                    "Royalties can only be updated once per day".to_string(),
+                )
+                .to_string(),
+            );
+        }
+
+        #[test]
+        fn royalty_decrease_to_new_address_is_rate_limited() {
+            let params = mock_collection_params();
+            let custom_create_minter_msg =
+                mock_create_minter_init_msg(params, mock_init_extension(None, None));
+            let (mut app, contract) = custom_proper_instantiate(custom_create_minter_msg);
+            let creator = Addr::unchecked("creator".to_string());
+
+            let update_royalty_msg = |payment_address: &str, share: u64| Sg721ExecuteMsg::<
+                Empty,
+                Empty,
+            >::UpdateCollectionInfo {
+                collection_info: UpdateCollectionInfoMsg {
+                    creator: None,
+                    description: None,
+                    image: None,
+                    external_link: None,
+                    explicit_content: None,
+                    royalty_info: Some(Some(RoyaltyInfoResponse {
+                        payment_address: payment_address.to_string(),
+                        share: Decimal::percent(share),
+                    })),
+                },
+            };
+
+            // a decrease redirecting royalties waits for the cooldown like any other update
+            let res = app.execute_contract(
+                creator.clone(),
+                contract.clone(),
+                &update_royalty_msg("new_payee", 8),
+                &[],
+            );
+            assert_error(
+                res,
+                ContractError::InvalidRoyalties(
+                    "Royalties can only be updated once per day".to_string(),
+                )
+                .to_string(),
+            );
+
+            let res: CollectionInfoResponse = app
+                .wrap()
+                .query_wasm_smart(contract.clone(), &QueryMsg::CollectionInfo {})
+                .unwrap();
+            assert_eq!(
+                res.royalty_info.unwrap().payment_address,
+                creator.to_string()
+            );
+
+            // the same decrease to the current address goes through
+            let res = app.execute_contract(
+                creator.clone(),
+                contract,
+                &update_royalty_msg(creator.as_str(), 8),
+                &[],
+            );
+            assert!(res.is_ok());
+        }
+
         #[test]
         fn update_collection_info() {
             // customize params so external_link is None
@@ -336,10 +458,10 @@ mod tests {
             );
             assert!(res.is_ok());
 
-            // royalty cannot be updated before a day has passed
+            // royalty cannot be raised before a day has passed
             let royalty_info: Option<RoyaltyInfoResponse> = Some(RoyaltyInfoResponse {
                 payment_address: creator.to_string(),
-                share: Decimal::percent(7),
+                share: Decimal::percent(11),
             });
             let res = app.execute_contract(
                 creator.clone(),
@@ -564,6 +686,8 @@ mod tests {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(0),
                     }),
+                    max_supply: None,
+                    enforce_royalties_on_transfer: None,
                 },
                 ..mock_collection_params()
             };
@@ -605,6 +729,8 @@ mod tests {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(91),
                     }),
+                    max_supply: None,
+                    enforce_royalties_on_transfer: None,
                 },
                 ..mock_collection_params()
             };
@@ -646,6 +772,8 @@ mod tests {
                         payment_address: "creator".to_string(),
                         share: Decimal::percent(3),
                     }),
+                    max_supply: None,
+                    enforce_royalties_on_transfer: None,
                 },
                 ..mock_collection_params()
             };
@@ -685,6 +813,8 @@ mod tests {
                     start_trading_time: None,
                     explicit_content: Some(false),
                     royalty_info: None,
+                    max_supply: None,
+                    enforce_royalties_on_transfer: None,
                 },
                 ..mock_collection_params()
             };
@@ -803,6 +933,200 @@ mod tests {
         }
     }
 
+    mod max_supply {
+        use super::*;
+        use sg721_base::msg::{QueryMsg, SupplyResponse};
+
+        #[test]
+        fn mint_stops_at_max_supply() {
+            let (mut app, factory_contract) = proper_instantiate_factory();
+            let sg721_id = app.store_code(contract_sg721_base());
+            let msg = InstantiateMsg {
+                name: "sg721".to_string(),
+                symbol: "STARGAZE".to_string(),
+                minter: ADMIN.to_string(),
+                collection_info: CollectionInfo {
+                    creator: ADMIN.to_string(),
+                    description: "description".to_string(),
+                    image: "description".to_string(),
+                    external_link: None,
+                    explicit_content: None,
+                    start_trading_time: None,
+                    royalty_info: None,
+                    max_supply: Some(2),
+                    enforce_royalties_on_transfer: None,
+                },
+            };
+            // sg721 must be instantiated by a contract
+            let contract = app
+                .instantiate_contract(
+                    sg721_id,
+                    factory_contract.addr(),
+                    &msg,
+                    &[],
+                    "sg721-max-supply",
+                    None,
+                )
+                .unwrap();
+
+            let mint_msg = |token_id: &str| Sg721ExecuteMsg::<Empty, Empty>::Mint {
+                token_id: token_id.to_string(),
+                owner: ADMIN.to_string(),
+                token_uri: None,
+                extension: Empty {},
+            };
+            for token_id in ["1", "2"] {
+                let res = app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    contract.clone(),
+                    &mint_msg(token_id),
+                    &[],
+                );
+                assert!(res.is_ok());
+            }
+
+            let res = app.execute_contract(
+                Addr::unchecked(ADMIN),
+                contract.clone(),
+                &mint_msg("3"),
+                &[],
+            );
+            assert_error(res, "MaxSupplyReached".to_string());
+
+            let res: SupplyResponse = app
+                .wrap()
+                .query_wasm_smart(contract, &QueryMsg::Supply {})
+                .unwrap();
+            assert_eq!(res.minted, 2);
+            assert_eq!(res.max_supply, Some(2));
+        }
+    }
+
+    mod royalty_enforcement {
+        use super::*;
+        use cosmwasm_std::{coins, Decimal};
+        use sg721::RoyaltyInfoResponse;
+
+        const BUYER: &str = "buyer";
+        const ROYALTY_ADDR: &str = "royalty";
+
+        fn instantiate_collection(app: &mut App, factory: Addr, enforce: bool) -> Addr {
+            let sg721_id = app.store_code(contract_sg721_base());
+            let msg = InstantiateMsg {
+                name: "sg721".to_string(),
+                symbol: "STARGAZE".to_string(),
+                minter: ADMIN.to_string(),
+                collection_info: CollectionInfo {
+                    creator: ADMIN.to_string(),
+                    description: "description".to_string(),
+                    image: "description".to_string(),
+                    external_link: None,
+                    explicit_content: None,
+                    start_trading_time: None,
+                    royalty_info: Some(RoyaltyInfoResponse {
+                        payment_address: ROYALTY_ADDR.to_string(),
+                        share: Decimal::percent(10),
+                    }),
+                    max_supply: None,
+                    enforce_royalties_on_transfer: Some(enforce),
+                },
+            };
+            let contract = app
+                .instantiate_contract(sg721_id, factory, &msg, &[], "sg721-royalties", None)
+                .unwrap();
+            let mint_msg = Sg721ExecuteMsg::<Empty, Empty>::Mint {
+                token_id: "1".to_string(),
+                owner: ADMIN.to_string(),
+                token_uri: None,
+                extension: Empty {},
+            };
+            app.execute_contract(Addr::unchecked(ADMIN), contract.clone(), &mint_msg, &[])
+                .unwrap();
+            app.sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: ADMIN.to_string(),
+                amount: coins(1_000, NATIVE_DENOM),
+            }))
+            .unwrap();
+            contract
+        }
+
+        #[test]
+        fn unpaid_transfer_fails() {
+            let (mut app, factory_contract) = proper_instantiate_factory();
+            let contract = instantiate_collection(&mut app, factory_contract.addr(), true);
+
+            let transfer_msg = |sale_price| Sg721ExecuteMsg::<Empty, Empty>::TransferNft {
+                recipient: BUYER.to_string(),
+                token_id: "1".to_string(),
+                sale_price,
+            };
+            let res = app.execute_contract(
+                Addr::unchecked(ADMIN),
+                contract.clone(),
+                &transfer_msg(None),
+                &[],
+            );
+            assert_error(res, "RoyaltyNotPaid".to_string());
+
+            // 10% of the declared sale price is due
+            let res = app.execute_contract(
+                Addr::unchecked(ADMIN),
+                contract.clone(),
+                &transfer_msg(Some(coin(1_000, NATIVE_DENOM))),
+                &coins(50, NATIVE_DENOM),
+            );
+            assert_error(res, "RoyaltyNotPaid".to_string());
+
+            // declaring a zero price doesn't waive the royalty
+            let res = app.execute_contract(
+                Addr::unchecked(ADMIN),
+                contract,
+                &transfer_msg(Some(coin(0, NATIVE_DENOM))),
+                &[],
+            );
+            assert_error(res, "RoyaltyNotPaid".to_string());
+        }
+
+        #[test]
+        fn unenforced_transfer_accepts_cw721_message() {
+            let (mut app, factory_contract) = proper_instantiate_factory();
+            let contract = instantiate_collection(&mut app, factory_contract.addr(), false);
+
+            // the message shape without a sale price keeps working
+            let transfer_msg = cw721ExecuteMsg::<Empty, Empty>::TransferNft {
+                recipient: BUYER.to_string(),
+                token_id: "1".to_string(),
+            };
+            let res = app.execute_contract(Addr::unchecked(ADMIN), contract, &transfer_msg, &[]);
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn paid_transfer_forwards_royalty() {
+            let (mut app, factory_contract) = proper_instantiate_factory();
+            let contract = instantiate_collection(&mut app, factory_contract.addr(), true);
+
+            let transfer_msg = Sg721ExecuteMsg::<Empty, Empty>::TransferNft {
+                recipient: BUYER.to_string(),
+                token_id: "1".to_string(),
+                sale_price: Some(coin(1_000, NATIVE_DENOM)),
+            };
+            let res = app.execute_contract(
+                Addr::unchecked(ADMIN),
+                contract,
+                &transfer_msg,
+                &coins(100, NATIVE_DENOM),
+            );
+            assert!(res.is_ok());
+
+            let balance = app
+                .wrap()
+                .query_balance(ROYALTY_ADDR, NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount.u128(), 100);
+        }
+    }
+
     mod sg721_mutable {
         use crate::common_setup::contract_boxes::App;
         use cosmwasm_std::{coin, Addr};
@@ -0,0 +1,123 @@
+use cosmwasm_std::{coin, Addr, Timestamp};
+use rs_merkle::MerkleTree;
+use sg2::tests::mock_collection_params_1;
+use sg_std::{GENESIS_MINT_START_TIME, NATIVE_DENOM};
+use vending_factory::msg::VendingMinterInitMsgExtension;
+use vending_minter_merkle_wl::msg::{QueryMsg, VerifyAllowlistProofResponse};
+use whitelist_mtree::tests::{hasher::SortingSha256Hasher, test_helpers::hash_and_build_tree};
+
+use crate::common_setup::contract_boxes::{
+    contract_sg721_base, contract_vending_factory, contract_vending_minter_merkle_wl,
+    custom_mock_app, App,
+};
+use crate::common_setup::msg::CodeIds;
+use crate::common_setup::setup_accounts_and_block::setup_accounts;
+use crate::common_setup::setup_minter::common::constants::MIN_MINT_PRICE;
+use crate::common_setup::setup_minter::common::minter_params::minter_params_all;
+use crate::common_setup::setup_minter::vending_minter::setup::configure_minter;
+use crate::common_setup::setup_whitelist_merkletree::{
+    setup_whitelist_mtree_contract, WHITELIST_AMOUNT,
+};
+
+type Tree = MerkleTree<SortingSha256Hasher>;
+
+fn setup_merkle_minter(router: &mut App, creator: &Addr, whitelist: Option<String>) -> Addr {
+    let num_tokens = 10;
+    let start_time = Timestamp::from_nanos(GENESIS_MINT_START_TIME + 10_000_000);
+    let init_msg = VendingMinterInitMsgExtension {
+        base_token_uri: "ipfs://aldkfjads".to_string(),
+        payment_address: None,
+        start_time,
+        num_tokens,
+        mint_price: coin(MIN_MINT_PRICE, NATIVE_DENOM),
+        per_address_limit: 1,
+        whitelist,
+    };
+    let code_ids = CodeIds {
+        minter_code_id: router.store_code(contract_vending_minter_merkle_wl()),
+        factory_code_id: router.store_code(contract_vending_factory()),
+        sg721_code_id: router.store_code(contract_sg721_base()),
+    };
+    let minter_collection_response = configure_minter(
+        router,
+        creator.clone(),
+        vec![mock_collection_params_1(Some(start_time))],
+        vec![minter_params_all(num_tokens, None, None, Some(init_msg))],
+        code_ids,
+    );
+    minter_collection_response[0].minter.clone().unwrap()
+}
+
+#[test]
+fn check_verify_allowlist_proof() {
+    let mut router = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut router);
+    let addrs = vec![
+        buyer.to_string(),
+        "addr0002".to_string(),
+        "addr0003".to_string(),
+    ];
+    let tree: Tree = hash_and_build_tree(&addrs);
+    let whitelist_addr =
+        setup_whitelist_mtree_contract(&mut router, &creator, None, None, tree.root_hex().unwrap());
+    let minter_addr = setup_merkle_minter(&mut router, &creator, Some(whitelist_addr.to_string()));
+    let proof = tree.proof(&[0]).proof_hashes_hex();
+
+    let res: VerifyAllowlistProofResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::VerifyAllowlistProof {
+                address: buyer.to_string(),
+                proof: proof.clone(),
+            },
+        )
+        .unwrap();
+    assert!(res.valid);
+    assert_eq!(res.price, coin(WHITELIST_AMOUNT, NATIVE_DENOM));
+
+    // Addresses not on the list are reported as invalid
+    let res: VerifyAllowlistProofResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr.clone(),
+            &QueryMsg::VerifyAllowlistProof {
+                address: "addr0009".to_string(),
+                proof,
+            },
+        )
+        .unwrap();
+    assert!(!res.valid);
+
+    // So are malformed proofs
+    let res: VerifyAllowlistProofResponse = router
+        .wrap()
+        .query_wasm_smart(
+            minter_addr,
+            &QueryMsg::VerifyAllowlistProof {
+                address: buyer.to_string(),
+                proof: vec!["not a hash".to_string()],
+            },
+        )
+        .unwrap();
+    assert!(!res.valid);
+}
+
+#[test]
+fn check_verify_allowlist_proof_without_merkle_root() {
+    let mut router = custom_mock_app();
+    let (creator, buyer) = setup_accounts(&mut router);
+    let minter_addr = setup_merkle_minter(&mut router, &creator, None);
+
+    let res: Result<VerifyAllowlistProofResponse, _> = router.wrap().query_wasm_smart(
+        minter_addr,
+        &QueryMsg::VerifyAllowlistProof {
+            address: buyer.to_string(),
+            proof: vec![],
+        },
+    );
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("No merkle root configured"));
+}